@@ -33,6 +33,84 @@ impl EntangledSystem {
         }
     }
 
+    /// Returns a particle's amplitude: the summed weights of the edges from its
+    /// `core_node` to the nodes listed in its `state_edges`.
+    pub fn particle_amplitude(&self, particle: &ParticleResonance) -> Complex<f32> {
+        self.state_weights(particle).into_iter().sum()
+    }
+
+    /// Returns the weights of a particle's state edges, one per entry of `state_edges`.
+    /// Parallel edges to the same destination are summed; a missing edge counts as zero.
+    pub fn state_weights(&self, particle: &ParticleResonance) -> Vec<Complex<f32>> {
+        let edges = self.graph.get_edges_for_node(particle.core_node).unwrap_or_default();
+        particle
+            .state_edges
+            .iter()
+            .map(|dest| edges.iter().filter(|e| e.destination == *dest).map(|e| e.weight).sum())
+            .collect()
+    }
+
+    /// Measures how strongly the two particles' states are correlated.
+    ///
+    /// This is the normalized inner product `Re(Σ aₖ·conj(bₖ)) / (‖a‖·‖b‖)` of the two
+    /// particles' state weights, paired in order (truncated to the shorter pattern).
+    /// Values near `±1` indicate strong (anti-)entanglement and values near `0`
+    /// independent particles. Returns `0.0` if either particle has no amplitude.
+    pub fn entanglement_correlation(&self) -> f64 {
+        let a = self.state_weights(&self.particles.0);
+        let b = self.state_weights(&self.particles.1);
+        let n = a.len().min(b.len());
+        let (a, b) = (&a[..n], &b[..n]);
+
+        let norm = |v: &[Complex<f32>]| v.iter().map(|w| w.norm_sqr() as f64).sum::<f64>().sqrt();
+        let denominator = norm(a) * norm(b);
+        if denominator < 1e-12 {
+            return 0.0;
+        }
+        let inner: f64 = a.iter().zip(b).map(|(x, y)| (x * y.conj()).re as f64).sum();
+        inner / denominator
+    }
+
+    /// Measures how closely a pulse matches the system's combined particle amplitude.
+    ///
+    /// The pulse is read as the phasor `amplitude · e^(i·phase)`, and the feedback's
+    /// `correlation_strength` is its distance from the sum of both particles' amplitudes,
+    /// so a pulse that exactly mirrors the system scores zero.
+    pub fn measure(&self, pulse: &EntropyPulse) -> FeedbackSignal {
+        let combined = self.particle_amplitude(&self.particles.0) + self.particle_amplitude(&self.particles.1);
+        let probe = Complex::from_polar(pulse.amplitude as f32, pulse.phase as f32);
+        FeedbackSignal { correlation_strength: (probe - combined).norm() as f64 }
+    }
+
+    /// Applies a sequence of pulses, measuring each one, and returns the feedback time series.
+    ///
+    /// After each measurement the pulse interacts with the system: the state edges of the
+    /// first particle are rotated by `frequency` radians, and those of its entangled partner
+    /// by the opposite angle. The combined amplitude therefore evolves from step to step.
+    pub fn run_sequence(&mut self, pulses: &[EntropyPulse]) -> Vec<FeedbackSignal> {
+        pulses
+            .iter()
+            .map(|pulse| {
+                let feedback = self.measure(pulse);
+                let rotation = Complex::from_polar(1.0, pulse.frequency as f32);
+                rotate_particle(&mut self.graph, &self.particles.0, rotation);
+                rotate_particle(&mut self.graph, &self.particles.1, rotation.conj());
+                feedback
+            })
+            .collect()
+    }
+
+    /// Relaxes the system by scaling every edge weight in the graph by `factor`.
+    ///
+    /// With `0 < factor < 1` this damps the weights toward zero and keeps repeated pulse
+    /// applications from saturating the system. Factors around `0.9`-`0.99` give a gentle
+    /// per-step decay; smaller factors forget past pulses within a few steps.
+    pub fn relax(&mut self, factor: f32) {
+        for edge in self.graph.all_edges_mut() {
+            edge.weight *= factor;
+        }
+    }
+
     /// (Internal utility) Creates a particle representation at a specific node in the graph.
     ///
     /// This function initializes a resonance pattern by setting the weights of all edges
@@ -57,6 +135,8 @@ impl EntangledSystem {
                 pattern_nodes: state_edge_destinations.clone(),
                 core_node: node_id,
                 state_edges: state_edge_destinations,
+                phase: 0.0,
+                base_frequency: 0.0,
             })
         } else {
             // If the node doesn't exist, we cannot create a particle there.
@@ -65,6 +145,15 @@ impl EntangledSystem {
     }
 }
 
+/// Multiplies the weights of a particle's state edges by `rotation`.
+fn rotate_particle(graph: &mut FractalGraph<Complex<f32>>, particle: &ParticleResonance, rotation: Complex<f32>) {
+    if let Some(edges) = graph.get_edges_for_node_mut(particle.core_node) {
+        for edge in edges.iter_mut().filter(|e| particle.state_edges.contains(&e.destination)) {
+            edge.weight *= rotation;
+        }
+    }
+}
+
 /// Represents a particle as a stable, oscillating pattern of states within the `FractalGraph`.
 ///
 /// A particle is not a single point but a subgraph of resonating nodes and edges,
@@ -76,6 +165,27 @@ pub struct ParticleResonance {
     pub core_node: NodeId,
     /// The specific edges whose weights (amplitudes) oscillate to represent the particle's state.
     pub state_edges: Vec<NodeId>,
+    /// The current phase of the oscillation, in radians within `[0, 2π)`.
+    pub phase: f32,
+    /// The oscillation frequency, in cycles per unit of time.
+    pub base_frequency: f64,
+}
+
+impl ParticleResonance {
+    /// Advances the particle's oscillation by `dt` and writes it onto the graph.
+    ///
+    /// The phase moves forward by `2π · base_frequency · dt`, and every state edge keeps
+    /// its amplitude but takes on the new phase, i.e. `Complex::from_polar(amp, phase)`.
+    pub fn oscillate(&mut self, graph: &mut FractalGraph<Complex<f32>>, dt: f64) {
+        let advance = (std::f64::consts::TAU * self.base_frequency * dt) as f32;
+        self.phase = (self.phase + advance).rem_euclid(std::f32::consts::TAU);
+
+        if let Some(edges) = graph.get_edges_for_node_mut(self.core_node) {
+            for edge in edges.iter_mut().filter(|e| self.state_edges.contains(&e.destination)) {
+                edge.weight = Complex::from_polar(edge.weight.norm(), self.phase);
+            }
+        }
+    }
 }
 
 /// Represents an action performed on the `EntangledSystem` to probe its state.
@@ -87,6 +197,8 @@ pub struct EntropyPulse {
     pub frequency: f64,
     pub amplitude: f64,
     pub waveform: String, // e.g., "sine", "square"
+    /// The phase offset of the pulse, in radians.
+    pub phase: f64,
 }
 
 /// Represents the result of a measurement, used as feedback for the learning algorithm.
@@ -113,8 +225,27 @@ pub trait ProbabilisticSearch {
     /// Updates the agent's internal beliefs based on the feedback from the last guess.
     /// This is the "learning" step.
     fn update(&mut self, feedback: &FeedbackSignal, last_guess: &EntropyPulse);
+
+    /// Proposes a pulse that satisfies the given constraint, re-sampling up to
+    /// `MAX_PROPOSAL_ATTEMPTS` times.
+    ///
+    /// If no valid pulse is found within the attempt budget, the last proposal is
+    /// returned unchanged, so callers that require a guarantee should re-check it.
+    fn propose_valid(&self, constraint: &dyn SymmetryConstraint) -> EntropyPulse {
+        let mut guess = self.propose_best_guess();
+        for _ in 1..MAX_PROPOSAL_ATTEMPTS {
+            if constraint.is_valid(&guess) {
+                break;
+            }
+            guess = self.propose_best_guess();
+        }
+        guess
+    }
 }
 
+/// The number of proposals `ProbabilisticSearch::propose_valid` draws before giving up.
+pub const MAX_PROPOSAL_ATTEMPTS: usize = 100;
+
 /// Defines the behavior of a symmetry constraint engine.
 ///
 /// This trait can be used to enforce specific rules or symmetries on the system,
@@ -122,4 +253,22 @@ pub trait ProbabilisticSearch {
 pub trait SymmetryConstraint {
     /// Checks if a proposed `EntropyPulse` adheres to the system's constraints.
     fn is_valid(&self, pulse: &EntropyPulse) -> bool;
-}
\ No newline at end of file
+}
+
+/// A constraint that only admits pulses within a frequency and amplitude band.
+///
+/// Both ranges are inclusive.
+#[derive(Debug, Clone)]
+pub struct BandConstraint {
+    pub min_freq: f64,
+    pub max_freq: f64,
+    pub min_amp: f64,
+    pub max_amp: f64,
+}
+
+impl SymmetryConstraint for BandConstraint {
+    fn is_valid(&self, pulse: &EntropyPulse) -> bool {
+        (self.min_freq..=self.max_freq).contains(&pulse.frequency)
+            && (self.min_amp..=self.max_amp).contains(&pulse.amplitude)
+    }
+}
@@ -0,0 +1,95 @@
+//! Implements a simulated-annealing alternative to the Bayesian search in `bayes`.
+//!
+//! `SimulatedAnnealing` walks a single candidate `EntropyPulse` through the parameter
+//! space, occasionally accepting worse guesses so that it can escape local minima. As the
+//! temperature cools, the walk settles into exploitation. Because it implements
+//! `ProbabilisticSearch`, it can be used interchangeably with `FrequencyBeliefSpace`.
+
+use crate::ai::{EntropyPulse, FeedbackSignal, ProbabilisticSearch};
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+/// A probabilistic search that explores by perturbing a current pulse and accepting
+/// moves according to the Metropolis criterion.
+pub struct SimulatedAnnealing {
+    /// The pulse the walk is currently positioned at.
+    pub current: EntropyPulse,
+    /// The feedback signal recorded for `current`.
+    pub current_feedback: FeedbackSignal,
+    /// The best `EntropyPulse` found so far during the search.
+    pub best_guess: EntropyPulse,
+    /// The feedback signal corresponding to the `best_guess`, holding the smallest error.
+    pub best_feedback: FeedbackSignal,
+    /// Controls both the size of proposed perturbations and the tolerance for worse guesses.
+    pub temperature: f64,
+    /// The factor applied to `temperature` after every update (e.g. 0.99).
+    pub cooling: f64,
+}
+
+impl SimulatedAnnealing {
+    /// Creates a new `SimulatedAnnealing` search starting from an initial pulse.
+    pub fn new(initial: EntropyPulse, temperature: f64, cooling: f64) -> Self {
+        let no_feedback = FeedbackSignal {
+            correlation_strength: f64::MAX,
+        };
+        Self {
+            current: initial.clone(),
+            current_feedback: no_feedback.clone(),
+            best_guess: initial,
+            best_feedback: no_feedback,
+            temperature,
+            cooling,
+        }
+    }
+
+    /// Proposes a neighbour of the current pulse as `propose_best_guess` does, drawing
+    /// from `rng`.
+    pub fn propose_with_rng(&self, rng: &mut impl Rng) -> EntropyPulse {
+        let freq_step: f64 = rng.sample(StandardNormal);
+        let amp_step: f64 = rng.sample(StandardNormal);
+
+        EntropyPulse {
+            frequency: self.current.frequency + freq_step * self.temperature,
+            amplitude: self.current.amplitude + amp_step * self.temperature / 50.0,
+            ..self.current.clone()
+        }
+    }
+
+    /// Applies the Metropolis update as `update` does, drawing the acceptance test from `rng`.
+    pub fn update_with_rng(&mut self, feedback: &FeedbackSignal, last_guess: &EntropyPulse, rng: &mut impl Rng) {
+        if feedback.correlation_strength < self.best_feedback.correlation_strength {
+            self.best_feedback = feedback.clone();
+            self.best_guess = last_guess.clone();
+        }
+
+        let delta = feedback.correlation_strength - self.current_feedback.correlation_strength;
+        let accept = delta <= 0.0 || rng.random_range(0.0..1.0) < (-delta / self.temperature).exp();
+        if accept {
+            self.current = last_guess.clone();
+            self.current_feedback = feedback.clone();
+        }
+
+        // Keep the temperature strictly positive so the acceptance test stays well-defined.
+        self.temperature = (self.temperature * self.cooling).max(1e-9);
+    }
+}
+
+impl ProbabilisticSearch for SimulatedAnnealing {
+    /// Proposes a neighbour of the current pulse.
+    ///
+    /// The frequency is perturbed by a normal step with a standard deviation equal to the
+    /// temperature. The amplitude uses a step 50 times smaller, mirroring the ratio of the
+    /// initial uncertainties in `FrequencyBeliefSpace`.
+    fn propose_best_guess(&self) -> EntropyPulse {
+        self.propose_with_rng(&mut rand::rng())
+    }
+
+    /// Decides whether to move to the last guess, then cools the temperature.
+    ///
+    /// Better guesses are always accepted. A worse guess is accepted with probability
+    /// `exp(-delta / temperature)`, where `delta` is the increase in error.
+    fn update(&mut self, feedback: &FeedbackSignal, last_guess: &EntropyPulse) {
+        self.update_with_rng(feedback, last_guess, &mut rand::rng());
+    }
+}
+
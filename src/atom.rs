@@ -38,6 +38,30 @@ impl Metadata {
         }
         Ok(())
     }
+
+    /// The separator placed between domains when merging metadata from different domains.
+    pub const DOMAIN_SEPARATOR: &'static str = "+";
+
+    /// Combines two metadata records, preserving the context of both.
+    ///
+    /// Equal domains are kept as-is, while differing domains are joined with
+    /// `DOMAIN_SEPARATOR` (e.g. `"physics+linguistics"`). Descriptions are joined with
+    /// `"; "` when both are present and differ; otherwise whichever exists is kept.
+    pub fn merge(&self, other: &Metadata) -> Metadata {
+        let domain = if self.domain == other.domain {
+            self.domain.clone()
+        } else {
+            format!("{}{}{}", self.domain, Self::DOMAIN_SEPARATOR, other.domain)
+        };
+
+        let description = match (&self.description, &other.description) {
+            (Some(a), Some(b)) if a != b => Some(format!("{}; {}", a, b)),
+            (Some(a), _) => Some(a.clone()),
+            (None, b) => b.clone(),
+        };
+
+        Metadata { domain, description }
+    }
 }
 
 impl AsRef<Metadata> for Metadata {
@@ -90,6 +114,21 @@ where
     pub fn metadata(&self) -> &Metadata {
         &self.metadata
     }
+
+    /// Combines two atoms into one, preserving the context of both.
+    ///
+    /// The values are combined with `combine_value`, the tags are merged with
+    /// `TagSet::merge`, and the metadata with `Metadata::merge`.
+    ///
+    /// # Errors
+    /// Returns `AtomError` if the merged components fail validation.
+    pub fn merge(&self, other: &Self, combine_value: impl Fn(&T, &T) -> T) -> Result<Self, AtomError> {
+        Self::new(
+            combine_value(&self.value, &other.value),
+            self.tags.merge(&other.tags),
+            self.metadata.merge(&other.metadata),
+        )
+    }
 }
 
 /// Defines specific error cases that can occur when constructing or manipulating a `TagSet`.
@@ -101,6 +140,8 @@ pub enum TagSetError {
     EmptyTag(String),
     /// A duplicate tag was found in the initial collection.
     DuplicateTag(String),
+    /// Removing the tag would leave the `TagSet` empty.
+    LastTag(String),
 }
 
 /// A validated, sorted, and unique set of string tags.
@@ -167,6 +208,99 @@ impl TagSet {
     pub fn contains(&self, tag: &str) -> bool {
         self.tags.binary_search(&tag.to_string()).is_ok()
     }
+
+    /// Checks if any tag in the set starts with `prefix`.
+    /// Thanks to the sorted storage, this is a binary search for the first tag not less
+    /// than `prefix`, since any tag with that prefix must be found there.
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        let start = self.tags.partition_point(|t| t.as_str() < prefix);
+        self.tags.get(start).is_some_and(|t| t.starts_with(prefix))
+    }
+
+    /// Inserts a tag, keeping the set sorted and unique.
+    ///
+    /// The tag is trimmed before insertion, as in `new`.
+    ///
+    /// # Returns
+    /// `true` if the tag was newly inserted, `false` if it was already present.
+    ///
+    /// # Errors
+    /// Returns `TagSetError::EmptyTag` if the tag is empty or whitespace-only.
+    pub fn insert(&mut self, tag: impl Into<String>) -> Result<bool, TagSetError> {
+        let tag = tag.into().trim().to_string();
+        if tag.is_empty() {
+            return Err(TagSetError::EmptyTag(tag));
+        }
+        match self.tags.binary_search(&tag) {
+            Ok(_) => Ok(false),
+            Err(pos) => {
+                self.tags.insert(pos, tag);
+                Ok(true)
+            }
+        }
+    }
+
+    /// Removes a tag from the set.
+    ///
+    /// # Returns
+    /// `true` if the tag was present and removed, `false` if it was not found.
+    ///
+    /// # Errors
+    /// Returns `TagSetError::LastTag` if the tag is the only one left, since
+    /// removing it would violate the non-empty invariant.
+    pub fn remove(&mut self, tag: &str) -> Result<bool, TagSetError> {
+        match self.tags.binary_search_by(|t| t.as_str().cmp(tag)) {
+            Ok(_) if self.tags.len() == 1 => Err(TagSetError::LastTag(tag.to_string())),
+            Ok(pos) => {
+                self.tags.remove(pos);
+                Ok(true)
+            }
+            Err(_) => Ok(false),
+        }
+    }
+
+    // --- Set Algebra ---
+
+    /// Returns a new `TagSet` containing every tag present in either set.
+    ///
+    /// Since both operands are non-empty, the union is always a valid `TagSet`.
+    pub fn union(&self, other: &TagSet) -> TagSet {
+        let mut tags: Vec<String> = self.tags.iter().chain(other.tags.iter()).cloned().collect();
+        tags.sort_unstable();
+        tags.dedup();
+        Self { tags }
+    }
+
+    /// Merges two tag sets, keeping every tag from both. This is an alias for `union`,
+    /// named to match `Metadata::merge` and `FractalAtom::merge`.
+    pub fn merge(&self, other: &TagSet) -> TagSet {
+        self.union(other)
+    }
+
+    /// Returns a new `TagSet` containing only the tags present in both sets.
+    ///
+    /// # Errors
+    /// Returns `TagSetError::EmptyCollection` if the sets share no tags, as an
+    /// empty `TagSet` would violate the non-empty invariant.
+    pub fn intersection(&self, other: &TagSet) -> Result<TagSet, TagSetError> {
+        Self::from_sorted(self.tags.iter().filter(|t| other.contains(t)).cloned().collect())
+    }
+
+    /// Returns a new `TagSet` containing the tags of `self` that are not in `other`.
+    ///
+    /// # Errors
+    /// Returns `TagSetError::EmptyCollection` if every tag of `self` is also in `other`.
+    pub fn difference(&self, other: &TagSet) -> Result<TagSet, TagSetError> {
+        Self::from_sorted(self.tags.iter().filter(|t| !other.contains(t)).cloned().collect())
+    }
+
+    /// Wraps an already sorted, unique vector of tags, enforcing only the non-empty invariant.
+    fn from_sorted(tags: Vec<String>) -> Result<TagSet, TagSetError> {
+        if tags.is_empty() {
+            return Err(TagSetError::EmptyCollection);
+        }
+        Ok(Self { tags })
+    }
 }
 
 impl Default for TagSet {
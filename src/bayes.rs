@@ -3,18 +3,97 @@
 //! This module defines a `FrequencyBeliefSpace` which maintains a probabilistic model
 //! (a set of Gaussian distributions) about the optimal parameters for an `EntropyPulse`.
 //! It iteratively refines its beliefs to minimize an error metric from a `FeedbackSignal`.
+//!
+//! For searches over every pulse parameter (including waveform shape and phase offset),
+//! `BeliefSpace` generalizes the same strategy to multiple continuous dimensions plus a
+//! discrete distribution over waveforms.
 
 use crate::ai::{EntropyPulse, FeedbackSignal, ProbabilisticSearch};
-use rand_distr::{Distribution, Normal};
+use rand::Rng;
+use rand_distr::StandardNormal;
 
 /// A simple Gaussian (Normal) distribution used to model a belief about a parameter.
 /// The `mean` represents the current best guess, and `std_dev` represents the uncertainty
 /// or the scope of exploration.
+#[derive(Debug, Clone)]
 pub struct Gaussian {
     pub mean: f64,
     pub std_dev: f64,
 }
 
+impl Gaussian {
+    /// Evaluates the probability density function at `x`.
+    pub fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.std_dev;
+        (-0.5 * z * z).exp() / (self.std_dev * (2.0 * std::f64::consts::PI).sqrt())
+    }
+
+    /// Evaluates the cumulative distribution function at `x`.
+    pub fn cdf(&self, x: f64) -> f64 {
+        0.5 * (1.0 + erf((x - self.mean) / (self.std_dev * std::f64::consts::SQRT_2)))
+    }
+
+    /// Performs a conjugate normal-normal update, treating `self` as the prior and
+    /// `observation` as a measurement with known variance `obs_variance`.
+    ///
+    /// The posterior precision is the sum of the prior and observation precisions,
+    /// and the posterior mean is their precision-weighted average.
+    pub fn conjugate_update(&mut self, observation: f64, obs_variance: f64) {
+        let prior_precision = 1.0 / (self.std_dev * self.std_dev);
+        let obs_precision = 1.0 / obs_variance;
+        let posterior_precision = prior_precision + obs_precision;
+
+        self.mean = (prior_precision * self.mean + obs_precision * observation) / posterior_precision;
+        self.std_dev = (1.0 / posterior_precision).sqrt();
+    }
+
+    /// Draws a single sample from the distribution.
+    pub fn sample(&self, rng: &mut impl Rng) -> f64 {
+        let z: f64 = rng.sample(StandardNormal);
+        self.mean + self.std_dev * z
+    }
+
+    /// Nudges the mean towards `target` with an exponential moving average, then narrows
+    /// the distribution by `annealing` without letting it collapse below `MIN_STD_DEV`.
+    fn anneal_towards(&mut self, target: f64, annealing: f64) {
+        self.mean = (1.0 - LEARNING_RATE) * self.mean + LEARNING_RATE * target;
+        self.std_dev = (self.std_dev * annealing).max(MIN_STD_DEV);
+    }
+}
+
+/// How far each update moves a belief towards the best-known value.
+/// A higher rate means we move faster towards the best guess.
+const LEARNING_RATE: f64 = 0.15;
+
+/// The smallest standard deviation a belief is annealed to, so the search can always
+/// explore a little.
+const MIN_STD_DEV: f64 = 0.01;
+
+/// Replaces the remembered best guess if `feedback` has a smaller error than the best so far.
+fn remember_best(
+    best_guess: &mut EntropyPulse,
+    best_feedback: &mut FeedbackSignal,
+    feedback: &FeedbackSignal,
+    last_guess: &EntropyPulse,
+) {
+    // The goal is to minimize correlation_strength (error).
+    if feedback.correlation_strength < best_feedback.correlation_strength {
+        *best_feedback = feedback.clone();
+        *best_guess = last_guess.clone();
+    }
+}
+
+/// Approximates the error function using Abramowitz & Stegun formula 7.1.26.
+/// The maximum absolute error is about 1.5e-7.
+fn erf(x: f64) -> f64 {
+    let sign = x.signum();
+    let x = x.abs();
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let poly = t * (0.254829592
+        + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    sign * (1.0 - poly * (-x * x).exp())
+}
+
 /// Represents the AI's entire belief system about the target `EntropyPulse`.
 ///
 /// It holds probability distributions for the pulse's frequency and amplitude,
@@ -41,6 +120,7 @@ impl FrequencyBeliefSpace {
             frequency: initial_freq,
             amplitude: initial_amp,
             waveform: "sine".to_string(),
+            phase: 0.0,
         };
 
         Self {
@@ -61,6 +141,15 @@ impl FrequencyBeliefSpace {
             },
         }
     }
+
+    /// Updates the frequency belief with a principled Bayesian posterior.
+    ///
+    /// Unlike the `update` heuristic (a moving average with fixed annealing), this treats
+    /// the current `frequency` Gaussian as a prior and `observation` as a noisy measurement
+    /// of the optimal frequency, so uncertainty shrinks in proportion to the evidence.
+    pub fn bayesian_update(&mut self, observation: f64, obs_variance: f64) {
+        self.frequency.conjugate_update(observation, obs_variance);
+    }
 }
 
 impl ProbabilisticSearch for FrequencyBeliefSpace {
@@ -69,16 +158,13 @@ impl ProbabilisticSearch for FrequencyBeliefSpace {
     /// This function represents the "exploration" phase. It generates a new guess
     /// based on the current mean (best belief) and standard deviation (uncertainty).
     fn propose_best_guess(&self) -> EntropyPulse {
-        // It's safe to unwrap here because the std_dev is controlled internally
-        // and is prevented from becoming non-positive in the update logic.
-        let freq_dist = Normal::new(self.frequency.mean, self.frequency.std_dev).unwrap();
-        let amp_dist = Normal::new(self.amplitude.mean, self.amplitude.std_dev).unwrap();
         let mut rng = rand::rng();
 
         EntropyPulse {
-            frequency: freq_dist.sample(&mut rng),
-            amplitude: amp_dist.sample(&mut rng),
+            frequency: self.frequency.sample(&mut rng),
+            amplitude: self.amplitude.sample(&mut rng),
             waveform: "sine".to_string(),
+            phase: 0.0,
         }
     }
 
@@ -87,31 +173,152 @@ impl ProbabilisticSearch for FrequencyBeliefSpace {
     /// This is the core of the learning algorithm. It adjusts the mean of its
     /// beliefs to move closer to the best-known solution and reduces the
     /// standard deviation to narrow the search space over time (exploitation).
+    /// Only the frequency belief is refined; the amplitude belief stays as initialized.
+    fn update(&mut self, feedback: &FeedbackSignal, last_guess: &EntropyPulse) {
+        remember_best(&mut self.best_guess, &mut self.best_feedback, feedback, last_guess);
+        self.frequency.anneal_towards(self.best_guess.frequency, 0.9);
+    }
+}
+
+/// A multi-parameter belief space covering every dimension of an `EntropyPulse`.
+///
+/// Continuous parameters are modeled by a `Vec<Gaussian>`, one per searched dimension,
+/// while the waveform shape is modeled by a discrete distribution of weights.
+/// `FrequencyBeliefSpace` remains available as the simpler two-parameter variant.
+pub struct BeliefSpace {
+    /// The pulse parameters being searched, as indices such as `BeliefSpace::FREQUENCY`.
+    /// Parameters that are not listed stay fixed at their value in `best_guess`.
+    pub dimensions: Vec<usize>,
+    /// The belief distributions for the searched parameters, in the order of `dimensions`.
+    pub parameters: Vec<Gaussian>,
+    /// The discrete belief over waveform shapes, as `(waveform, probability)` pairs.
+    pub waveforms: Vec<(String, f64)>,
+    /// The best `EntropyPulse` found so far during the search.
+    pub best_guess: EntropyPulse,
+    /// The feedback signal corresponding to the `best_guess`, holding the smallest error.
+    pub best_feedback: FeedbackSignal,
+    /// The factor applied to every standard deviation on each update. Searching several
+    /// dimensions at once needs slower annealing than `FrequencyBeliefSpace`'s fixed 0.9.
+    pub annealing: f64,
+}
+
+impl BeliefSpace {
+    /// Index of the frequency parameter.
+    pub const FREQUENCY: usize = 0;
+    /// Index of the amplitude parameter.
+    pub const AMPLITUDE: usize = 1;
+    /// Index of the phase-offset parameter.
+    pub const PHASE: usize = 2;
+    /// Every continuous parameter of an `EntropyPulse`.
+    pub const ALL_DIMENSIONS: [usize; 3] = [Self::FREQUENCY, Self::AMPLITUDE, Self::PHASE];
+
+    /// Creates a new `BeliefSpace` centred on an initial pulse that searches the given
+    /// `dimensions`, with a uniform belief over the candidate waveforms.
+    ///
+    /// As with `FrequencyBeliefSpace`, frequency starts with a wide standard deviation
+    /// to encourage broad exploration.
+    ///
+    /// # Panics
+    /// Panics if `waveforms` is empty or a dimension is not one of `ALL_DIMENSIONS`.
+    pub fn new(initial: EntropyPulse, dimensions: &[usize], waveforms: Vec<String>) -> Self {
+        assert!(!waveforms.is_empty(), "BeliefSpace requires at least one waveform");
+        let uniform = 1.0 / waveforms.len() as f64;
+
+        let parameters = dimensions
+            .iter()
+            .map(|&dimension| Gaussian {
+                mean: parameter(&initial, dimension),
+                std_dev: if dimension == Self::FREQUENCY { 50.0 } else { 1.0 },
+            })
+            .collect();
+
+        Self {
+            dimensions: dimensions.to_vec(),
+            parameters,
+            waveforms: waveforms.into_iter().map(|w| (w, uniform)).collect(),
+            best_guess: initial,
+            best_feedback: FeedbackSignal {
+                correlation_strength: f64::MAX,
+            },
+            annealing: 0.97,
+        }
+    }
+
+    /// Returns the belief over a parameter, or `None` if that parameter is not searched.
+    pub fn belief(&self, dimension: usize) -> Option<&Gaussian> {
+        let position = self.dimensions.iter().position(|&d| d == dimension)?;
+        self.parameters.get(position)
+    }
+
+    /// Proposes a new `EntropyPulse` as `propose_best_guess` does, drawing from `rng`.
+    pub fn propose_with_rng(&self, rng: &mut impl Rng) -> EntropyPulse {
+        let mut choice = rng.random_range(0.0..1.0);
+        let mut waveform = &self.waveforms[self.waveforms.len() - 1].0;
+        for (shape, probability) in &self.waveforms {
+            if choice < *probability {
+                waveform = shape;
+                break;
+            }
+            choice -= probability;
+        }
+
+        let mut guess = EntropyPulse { waveform: waveform.clone(), ..self.best_guess.clone() };
+        for (&dimension, belief) in self.dimensions.iter().zip(&self.parameters) {
+            *parameter_mut(&mut guess, dimension) = belief.sample(rng);
+        }
+        guess
+    }
+}
+
+/// Returns one of a pulse's continuous parameters, by index.
+///
+/// # Panics
+/// Panics if `dimension` is not one of `BeliefSpace::ALL_DIMENSIONS`.
+fn parameter(pulse: &EntropyPulse, dimension: usize) -> f64 {
+    match dimension {
+        BeliefSpace::FREQUENCY => pulse.frequency,
+        BeliefSpace::AMPLITUDE => pulse.amplitude,
+        BeliefSpace::PHASE => pulse.phase,
+        _ => panic!("unknown pulse parameter {dimension}"),
+    }
+}
+
+/// Returns a mutable reference to one of a pulse's continuous parameters, as `parameter` does.
+fn parameter_mut(pulse: &mut EntropyPulse, dimension: usize) -> &mut f64 {
+    match dimension {
+        BeliefSpace::FREQUENCY => &mut pulse.frequency,
+        BeliefSpace::AMPLITUDE => &mut pulse.amplitude,
+        BeliefSpace::PHASE => &mut pulse.phase,
+        _ => panic!("unknown pulse parameter {dimension}"),
+    }
+}
+
+impl ProbabilisticSearch for BeliefSpace {
+    /// Proposes a new `EntropyPulse` by sampling every parameter belief and drawing
+    /// a waveform in proportion to its current probability.
+    fn propose_best_guess(&self) -> EntropyPulse {
+        self.propose_with_rng(&mut rand::rng())
+    }
+
+    /// Updates every dimension of the belief space based on the feedback from the last guess.
+    ///
+    /// Each continuous belief follows the same rule as `FrequencyBeliefSpace`: its mean
+    /// moves towards the best-known value and its standard deviation is annealed. The
+    /// waveform distribution shifts probability mass towards the best-known waveform.
     fn update(&mut self, feedback: &FeedbackSignal, last_guess: &EntropyPulse) {
-        // Step 1: Check if the latest guess is better than the best one found so far.
-        // The goal is to minimize correlation_strength (error).
-        if feedback.correlation_strength < self.best_feedback.correlation_strength {
-            // We found a new best! Update our memory.
-            self.best_feedback = feedback.clone();
-            self.best_guess = last_guess.clone();
+        remember_best(&mut self.best_guess, &mut self.best_feedback, feedback, last_guess);
+
+        for (&dimension, belief) in self.dimensions.iter().zip(&mut self.parameters) {
+            belief.anneal_towards(parameter(&self.best_guess, dimension), self.annealing);
         }
 
-        // Step 2: Update the belief mean.
-        // Nudge the mean of our search distribution towards the best-known frequency.
-        // This is a form of exponential moving average, which stabilizes learning.
-        let learning_rate = 0.15; // A higher rate means we move faster towards the best guess.
-        self.frequency.mean =
-            (1.0 - learning_rate) * self.frequency.mean + learning_rate * self.best_guess.frequency;
-
-        // Step 3: Reduce exploration over time (annealing).
-        // Shrink the standard deviation to "zoom in" on the promising area.
-        // This shifts the strategy from exploration to exploitation.
-        self.frequency.std_dev *= 0.9;
-
-        // Step 4: Prevent the search space from collapsing entirely.
-        // A minimum standard deviation ensures the AI can always explore a little.
-        if self.frequency.std_dev < 0.01 {
-            self.frequency.std_dev = 0.01;
+        // Moving a fraction of the mass onto the best waveform keeps the weights summing to 1.
+        // A small share is always spread uniformly so that no waveform is ruled out entirely.
+        let exploration = 0.05 / self.waveforms.len() as f64;
+        for (shape, probability) in &mut self.waveforms {
+            let indicator = if *shape == self.best_guess.waveform { 1.0 } else { 0.0 };
+            let shifted = (1.0 - LEARNING_RATE) * *probability + LEARNING_RATE * indicator;
+            *probability = 0.95 * shifted + exploration;
         }
     }
-}
\ No newline at end of file
+}
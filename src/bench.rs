@@ -0,0 +1,75 @@
+//! Provides a lightweight timing harness for generator/critic throughput.
+//!
+//! This is not a statistical benchmark framework; it simply instruments one run of a
+//! `GeneratorCriticLoop` configuration so that `count` and `iterations` can be tuned.
+
+use crate::criticloop::GeneratorCriticLoop;
+use crate::field::FractalField;
+use crate::traits::Generator;
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// Throughput measurements from `benchmark_loop`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchStats {
+    /// The number of candidates produced by the generator during the timed run.
+    pub candidates_generated: usize,
+    /// The number of times the critic suite scored a field during the timed run. This
+    /// includes re-scoring the incumbent best field, so it can exceed
+    /// `candidates_generated`.
+    pub fields_scored: usize,
+    /// The wall-clock time of the timed run.
+    pub elapsed: Duration,
+}
+
+impl BenchStats {
+    /// Returns the number of candidates generated per second, or `0.0` if no time elapsed.
+    pub fn candidates_per_second(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 { self.candidates_generated as f64 / secs } else { 0.0 }
+    }
+}
+
+/// Times one instrumented run of `loop_engine`.
+///
+/// First, `warmup` untimed runs are made and discarded. The timed run is then exactly
+/// what `GeneratorCriticLoop::run` does, including niching and early stopping, with the
+/// generator and critic suite wrapped to count the candidates they produce and score.
+pub fn benchmark_loop<G: Generator>(loop_engine: &GeneratorCriticLoop<G>, warmup: usize) -> BenchStats {
+    for _ in 0..warmup {
+        loop_engine.run();
+    }
+
+    let generator = CountingGenerator { inner: &loop_engine.generator, generated: Cell::new(0) };
+    let scored = Cell::new(0);
+    let score = |field: &FractalField| {
+        scored.set(scored.get() + 1);
+        loop_engine.critic_suite.score(field)
+    };
+
+    let start = Instant::now();
+    loop_engine.drive_with(&generator, &score, None, |_, _| {});
+    let elapsed = start.elapsed();
+
+    BenchStats { candidates_generated: generator.generated.get(), fields_scored: scored.get(), elapsed }
+}
+
+/// Wraps a generator, counting every candidate it produces.
+struct CountingGenerator<'a, G> {
+    inner: &'a G,
+    generated: Cell<usize>,
+}
+
+impl<G: Generator> Generator for CountingGenerator<'_, G> {
+    fn generate(&self) -> Vec<FractalField> {
+        let candidates = self.inner.generate();
+        self.generated.set(self.generated.get() + candidates.len());
+        candidates
+    }
+
+    fn mutate(&self, field: &FractalField) -> Vec<FractalField> {
+        let candidates = self.inner.mutate(field);
+        self.generated.set(self.generated.get() + candidates.len());
+        candidates
+    }
+}
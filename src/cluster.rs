@@ -0,0 +1,72 @@
+//! Provides k-means clustering of `FractalField` populations by their signatures.
+//!
+//! Each field is reduced to the feature vector of its `FractalSignature`, and Lloyd's
+//! algorithm groups the population using the same L1 metric as `FractalSignature::distance`.
+
+use crate::field::FractalField;
+use crate::signature::feature_distance;
+
+/// Partitions fields into `k` clusters using Lloyd's algorithm over signature features.
+///
+/// Centroids are seeded deterministically from the first `k` fields, so results are
+/// reproducible. Each iteration assigns every field to its nearest centroid and then moves
+/// each centroid to the mean of its members. The loop stops early once assignments settle.
+///
+/// # Returns
+/// A cluster label in `0..k` for each field, in input order. If `k` exceeds the number of
+/// fields it is reduced to match; if `k` is zero every field is labelled `0`.
+pub fn cluster_fields(fields: &[FractalField], k: usize, iterations: usize) -> Vec<usize> {
+    let features: Vec<[f32; 4]> = fields.iter().map(|f| f.signature().features()).collect();
+    let k = k.min(features.len());
+    if k == 0 {
+        return vec![0; features.len()];
+    }
+
+    let mut centroids: Vec<[f32; 4]> = features[..k].to_vec();
+    let mut labels = vec![0; features.len()];
+
+    for iteration in 0..iterations.max(1) {
+        // Assignment step: label each field with its nearest centroid.
+        let mut changed = false;
+        for (label, point) in labels.iter_mut().zip(&features) {
+            let nearest = nearest_centroid(point, &centroids);
+            if *label != nearest {
+                *label = nearest;
+                changed = true;
+            }
+        }
+        if !changed && iteration > 0 {
+            break;
+        }
+
+        // Update step: move each centroid to the mean of its members.
+        let mut sums = vec![[0.0f32; 4]; k];
+        let mut counts = vec![0usize; k];
+        for (label, point) in labels.iter().zip(&features) {
+            counts[*label] += 1;
+            for (sum, value) in sums[*label].iter_mut().zip(point) {
+                *sum += value;
+            }
+        }
+        for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+            // An empty cluster keeps its previous centroid.
+            if count > 0 {
+                *centroid = sum.map(|s| s / count as f32);
+            }
+        }
+    }
+
+    labels
+}
+
+/// Returns the index of the centroid closest to `point`, preferring the lowest index on ties.
+fn nearest_centroid(point: &[f32; 4], centroids: &[[f32; 4]]) -> usize {
+    let mut best = (0, f32::INFINITY);
+    for (i, centroid) in centroids.iter().enumerate() {
+        let distance = feature_distance(point, centroid);
+        if distance < best.1 {
+            best = (i, distance);
+        }
+    }
+    best.0
+}
@@ -5,9 +5,11 @@
 //! iteratively improving the solution over a set number of iterations.
 
 use crate::critics::CriticSuite;
+use crate::diversity::population_diversity;
 use crate::field::FractalField;
-use crate::looprep::LoopReport;
+use crate::looprep::{FullLoopReport, LoopReport, ScoreStats};
 use crate::traits::Generator;
+use std::cmp::Ordering;
 
 /// Manages an evolutionary loop that generates and evaluates `FractalField` candidates.
 pub struct GeneratorCriticLoop<G: Generator> {
@@ -17,6 +19,19 @@ pub struct GeneratorCriticLoop<G: Generator> {
     pub critic_suite: CriticSuite,
     /// The total number of iterations to run the loop.
     pub iterations: usize,
+    /// The strength of the niching pressure that keeps the population diverse.
+    ///
+    /// A candidate's effective score is `critic_score + diversity_weight * distance`, where
+    /// `distance` is the signature distance to the current best field. This rewards
+    /// candidates that move away from the incumbent. A weight of `0.0` disables niching.
+    ///
+    /// The bonus only decides which candidate of a generation is selected. The selected
+    /// candidate replaces the best field only if its raw critic score is higher.
+    pub diversity_weight: f32,
+    /// Stops the loop early after this many consecutive iterations without improving the
+    /// best field. `None` always runs the full `iterations`. Set it through
+    /// `GeneratorCriticLoopBuilder::patience`.
+    patience: Option<usize>,
 }
 
 impl<G: Generator> GeneratorCriticLoop<G> {
@@ -37,11 +52,12 @@ impl<G: Generator> GeneratorCriticLoop<G> {
     /// suite.add_critic(SymmetryCritic, 0.5);
     /// suite.add_critic(EntropyCritic, 0.5);
     ///
-    /// let loop_engine = GeneratorCriticLoop {
-    ///     generator,
-    ///     critic_suite: suite,
-    ///     iterations: 20,
-    /// };
+    /// let loop_engine = GeneratorCriticLoopBuilder::new()
+    ///     .generator(generator)
+    ///     .critics(suite)
+    ///     .iterations(20)
+    ///     .build()
+    ///     .unwrap();
     ///
     /// if let Some(best_field) = loop_engine.run() {
     ///     println!("Best field found with score: {}", loop_engine.critic_suite.score(&best_field));
@@ -49,68 +65,270 @@ impl<G: Generator> GeneratorCriticLoop<G> {
     /// */
     /// ```
     pub fn run(&self) -> Option<FractalField> {
-        let mut best_field: Option<FractalField> = None;
+        self.drive(None, |_, _| {})
+    }
+
+    /// Runs the loop starting from `seed` instead of the generator's initial population.
+    ///
+    /// The seed is the incumbent best field from the first iteration, so the result is
+    /// never scored lower than the seed, whatever the `diversity_weight`. This lets an interrupted run be resumed from
+    /// its last best field.
+    pub fn run_from(&self, seed: FractalField) -> Option<FractalField> {
+        self.drive(Some(seed), |_, _| {})
+    }
+
+    /// Runs the loop and returns a full report including the history of candidates.
+    ///
+    /// This method is useful for analysis and debugging, as it tracks every
+    /// candidate that was considered the best in its generation, along with its score.
+    pub fn run_with_report(&self) -> Option<LoopReport> {
+        self.evolve_with_report(None, Vec::new(), Vec::new(), ScoreStats::new())
+    }
+
+    /// Continues a previous run, seeding the loop with the report's `best_field`.
+    ///
+    /// The returned report extends the previous `history`, `diversity` and `score_stats`,
+    /// so it covers both runs.
+    pub fn resume_with_report(&self, previous: LoopReport) -> Option<LoopReport> {
+        self.evolve_with_report(
+            Some(previous.best_field),
+            previous.history,
+            previous.diversity,
+            previous.score_stats,
+        )
+    }
+
+    /// The loop shared by `run_with_report` and `resume_with_report`.
+    fn evolve_with_report(
+        &self,
+        seed: Option<FractalField>,
+        mut history: Vec<(FractalField, f32)>,
+        mut diversity: Vec<f32>,
+        mut score_stats: ScoreStats,
+    ) -> Option<LoopReport> {
+        let best_field = self.drive(seed, |candidates, selected| {
+            if let Some(best_candidate) = selected {
+                let score = self.critic_suite.score(best_candidate);
+                history.push((best_candidate.clone(), score));
+                score_stats.push(score);
+                diversity.push(population_diversity(candidates));
+            }
+        });
+
+        // If a best field was found, construct the report.
+        best_field.map(|f| LoopReport {
+            best_score: self.critic_suite.score(&f),
+            best_field: f, // f has been moved, so no clone needed here.
+            history,
+            diversity,
+            score_stats,
+        })
+    }
+
+    /// Runs the loop and returns a report of every candidate in every generation.
+    ///
+    /// Unlike `run_with_report`, which keeps only the best candidate per generation, this
+    /// records the whole scored population. Generations in which the generator produced no
+    /// candidates are omitted. Because of its memory cost, this is a separate, opt-in method.
+    pub fn run_with_full_report(&self) -> Option<FullLoopReport> {
+        let mut generations = Vec::new();
+        let best_field = self.drive(None, |candidates, _| {
+            if !candidates.is_empty() {
+                let scored = candidates
+                    .iter()
+                    .map(|c| (c.clone(), self.critic_suite.score(c)))
+                    .collect();
+                generations.push(scored);
+            }
+        });
+
+        best_field.map(|f| FullLoopReport {
+            best_score: self.critic_suite.score(&f),
+            best_field: f,
+            generations,
+        })
+    }
+
+    /// The generate-select-improve cycle behind every `run*` method.
+    ///
+    /// Each iteration, `observe` is given the generation's candidates and the candidate
+    /// selected from them, before the selected candidate is compared with the incumbent.
+    /// Returns the best field once the iterations or the `patience` run out.
+    fn drive<F>(&self, seed: Option<FractalField>, observe: F) -> Option<FractalField>
+    where
+        F: FnMut(&[FractalField], Option<&FractalField>),
+    {
+        self.drive_with(&self.generator, &|f| self.critic_suite.score(f), seed, observe)
+    }
+
+    /// `drive` with the generator and scoring function supplied by the caller.
+    ///
+    /// `benchmark_loop` uses this to count the work done by a real run.
+    pub(crate) fn drive_with<H, F>(
+        &self,
+        generator: &H,
+        score: &dyn Fn(&FractalField) -> f32,
+        seed: Option<FractalField>,
+        mut observe: F,
+    ) -> Option<FractalField>
+    where
+        H: Generator,
+        F: FnMut(&[FractalField], Option<&FractalField>),
+    {
+        let mut best_field = seed;
+        let mut stale = 0; // Iterations since the best field last improved.
 
         for _ in 0..self.iterations {
+            stale += 1;
             // Generate new candidates, either from scratch or by mutating the current best.
             let candidates = match &best_field {
-                Some(f) => self.generator.mutate(f),
-                None => self.generator.generate(),
+                Some(f) => generator.mutate(f),
+                None => generator.generate(),
             };
 
             // Find the best candidate from the new batch.
-            if let Some(best_candidate) = self.critic_suite.select_best(&candidates) {
+            let selected = self.select_candidate(score, &candidates, best_field.as_ref());
+            observe(&candidates, selected);
+
+            if let Some(best_candidate) = selected {
                 // Check if this candidate is better than our overall best so far.
                 let is_improvement = match &best_field {
-                    // If we have a current best, compare scores.
-                    Some(current_best) => self.critic_suite.score(best_candidate) > self.critic_suite.score(current_best),
+                    // If we have a current best, compare raw scores; the niching bonus
+                    // only applies to selection.
+                    Some(current_best) => score(best_candidate) > score(current_best),
                     // If we don't have a best yet, any candidate is an improvement.
                     None => true,
                 };
 
                 if is_improvement {
                     best_field = Some(best_candidate.clone());
+                    stale = 0;
                 }
             }
+
+            if self.out_of_patience(stale) {
+                break;
+            }
         }
 
         best_field
     }
 
-    /// Runs the loop and returns a full report including the history of candidates.
+    /// Whether `stale` iterations without improvement exhaust the loop's `patience`.
+    fn out_of_patience(&self, stale: usize) -> bool {
+        self.patience.is_some_and(|patience| stale >= patience)
+    }
+
+    /// Selects the candidate with the highest effective score: its `score` plus the
+    /// niching bonus for its signature distance from the incumbent.
     ///
-    /// This method is useful for analysis and debugging, as it tracks every
-    /// candidate that was considered the best in its generation, along with its score.
-    pub fn run_with_report(&self) -> Option<LoopReport> {
-        let mut best_field: Option<FractalField> = None;
-        let mut history = Vec::new();
+    /// Each candidate is scored once. Without niching this picks the same field as
+    /// `CriticSuite::select_best`.
+    fn select_candidate<'a>(
+        &self,
+        score: &dyn Fn(&FractalField) -> f32,
+        candidates: &'a [FractalField],
+        incumbent: Option<&FractalField>,
+    ) -> Option<&'a FractalField> {
+        let niche = incumbent.filter(|_| self.diversity_weight != 0.0).map(|best| best.signature());
+        candidates
+            .iter()
+            .map(|c| {
+                let bonus = match &niche {
+                    Some(signature) => self.diversity_weight * c.signature().distance(signature),
+                    None => 0.0,
+                };
+                (c, score(c) + bonus)
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+            .map(|(c, _)| c)
+    }
+}
 
-        for _ in 0..self.iterations {
-            let candidates = match &best_field {
-                Some(f) => self.generator.mutate(f),
-                None => self.generator.generate(),
-            };
+/// A builder for `GeneratorCriticLoop`, so loops can be configured option by option.
+///
+/// Unset options default to an empty `CriticSuite`, 100 iterations, no niching and no
+/// early stopping.
+///
+/// ```no_run
+/// use fractal_algebra::{CriticSuite, GeneratorCriticLoopBuilder, RandomFieldGenerator, SymmetryCritic};
+///
+/// let mut critics = CriticSuite::new();
+/// critics.add_critic(SymmetryCritic, 1.0);
+///
+/// let loop_engine = GeneratorCriticLoopBuilder::new()
+///     .generator(RandomFieldGenerator { count: 10, mutation_strength: 0.5 })
+///     .critics(critics)
+///     .iterations(50)
+///     .patience(10)
+///     .build()
+///     .expect("a generator was set");
+/// let best = loop_engine.run();
+/// ```
+pub struct GeneratorCriticLoopBuilder<G: Generator> {
+    generator: Option<G>,
+    critic_suite: CriticSuite,
+    iterations: usize,
+    diversity_weight: f32,
+    patience: Option<usize>,
+}
 
-            if let Some(best_candidate) = self.critic_suite.select_best(&candidates) {
-                let score = self.critic_suite.score(best_candidate);
-                history.push((best_candidate.clone(), score));
+impl<G: Generator> GeneratorCriticLoopBuilder<G> {
+    /// Creates a builder with the default options and no generator.
+    pub fn new() -> Self {
+        GeneratorCriticLoopBuilder {
+            generator: None,
+            critic_suite: CriticSuite::new(),
+            iterations: 100,
+            diversity_weight: 0.0,
+            patience: None,
+        }
+    }
 
-                let is_improvement = match &best_field {
-                    Some(current_best) => score > self.critic_suite.score(current_best),
-                    None => true,
-                };
+    /// Sets the generator. This is the only required option.
+    pub fn generator(mut self, generator: G) -> Self {
+        self.generator = Some(generator);
+        self
+    }
 
-                if is_improvement {
-                    best_field = Some(best_candidate.clone());
-                }
-            }
-        }
+    /// Sets the critic suite used to score candidates.
+    pub fn critics(mut self, critic_suite: CriticSuite) -> Self {
+        self.critic_suite = critic_suite;
+        self
+    }
 
-        // If a best field was found, construct the report.
-        best_field.map(|f| LoopReport {
-            best_score: self.critic_suite.score(&f),
-            best_field: f, // f has been moved, so no clone needed here.
-            history,
+    /// Sets the maximum number of iterations.
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Sets the niching strength; see `GeneratorCriticLoop::diversity_weight`.
+    pub fn diversity_weight(mut self, diversity_weight: f32) -> Self {
+        self.diversity_weight = diversity_weight;
+        self
+    }
+
+    /// Stops the loop after `patience` consecutive iterations without improvement.
+    pub fn patience(mut self, patience: usize) -> Self {
+        self.patience = Some(patience);
+        self
+    }
+
+    /// Builds the loop, or returns `None` if no generator was set.
+    pub fn build(self) -> Option<GeneratorCriticLoop<G>> {
+        Some(GeneratorCriticLoop {
+            generator: self.generator?,
+            critic_suite: self.critic_suite,
+            iterations: self.iterations,
+            diversity_weight: self.diversity_weight,
+            patience: self.patience,
         })
     }
-}
\ No newline at end of file
+}
+
+impl<G: Generator> Default for GeneratorCriticLoopBuilder<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -5,7 +5,8 @@
 //` approach to evaluating generated fields based on multiple criteria.
 
 use crate::field::FractalField;
-use crate::traits::Critic;
+use crate::traits::{Critic, FieldClass};
+use rand::Rng;
 use std::cmp::Ordering;
 
 /// A collection of weighted critics to provide a composite score for a `FractalField`.
@@ -40,22 +41,65 @@ impl CriticSuite {
     /// Calculates the total weighted score for a given `FractalField`.
     ///
     /// The score is the sum of `critic.score(field) * weight` for all critics in the suite.
+    /// The field's signature is computed once and shared by every critic.
     pub fn score(&self, field: &FractalField) -> f32 {
+        let signature = field.signature();
         self.critics
             .iter()
-            .map(|(critic, weight)| critic.score(field) * weight)
+            .map(|(critic, weight)| critic.score_with_signature(field, &signature) * weight)
             .sum()
     }
 
+    /// Returns the Pareto front of `fields`: those not dominated by any other field.
+    ///
+    /// A field dominates another if its raw score is at least as high on every critic and
+    /// strictly higher on at least one. Weights are ignored, so trade-offs between critics
+    /// stay visible instead of being collapsed into one number. Fields are returned in
+    /// their original order.
+    pub fn pareto_front<'a>(&self, fields: &'a [FractalField]) -> Vec<&'a FractalField> {
+        let scores: Vec<Vec<f32>> = fields
+            .iter()
+            .map(|f| {
+                let signature = f.signature();
+                self.critics.iter().map(|(critic, _)| critic.score_with_signature(f, &signature)).collect()
+            })
+            .collect();
+        let dominates = |a: &[f32], b: &[f32]| {
+            a.iter().zip(b).all(|(x, y)| x >= y) && a.iter().zip(b).any(|(x, y)| x > y)
+        };
+
+        fields
+            .iter()
+            .zip(&scores)
+            .filter(|(_, own)| !scores.iter().any(|other| dominates(other, own)))
+            .map(|(field, _)| field)
+            .collect()
+    }
+
+    /// Breaks the composite score down by critic.
+    ///
+    /// Returns one `(name, raw_score, weighted_contribution)` tuple per critic, in the
+    /// order they were added. The contributions sum to `score(field)`.
+    pub fn score_breakdown(&self, field: &FractalField) -> Vec<(String, f32, f32)> {
+        let signature = field.signature();
+        self.critics
+            .iter()
+            .map(|(critic, weight)| {
+                let raw = critic.score_with_signature(field, &signature);
+                (critic.name().to_string(), raw, raw * weight)
+            })
+            .collect()
+    }
+
     /// Classifies a field based on the highest-weighted critic in the suite.
     ///
     /// This can be used to assign a primary "label" or "category" to a field.
-    pub fn classify(&self, field: &FractalField) -> String {
+    pub fn classify(&self, field: &FractalField) -> FieldClass {
         // Find the critic with the maximum weight.
         if let Some((critic, _)) = self.critics.iter().max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal)) {
             critic.classify(&field.signature())
         } else {
-            "unclassified".to_string()
+            FieldClass::Unclassified
         }
     }
 
@@ -73,4 +117,57 @@ impl CriticSuite {
                 score_a.partial_cmp(&score_b).unwrap_or(Ordering::Equal)
             })
     }
-}
\ No newline at end of file
+
+    /// Selects a field by tournament: samples `tournament_size` candidates uniformly (with
+    /// replacement) and returns the highest-scoring of them.
+    ///
+    /// Smaller tournaments give weaker candidates a chance to win, which slows premature
+    /// convergence compared to `select_best`. A `tournament_size` of zero is treated as one.
+    /// Returns `None` if `fields` is empty.
+    pub fn tournament_select<'a>(
+        &self,
+        fields: &'a [FractalField],
+        tournament_size: usize,
+        rng: &mut impl Rng,
+    ) -> Option<&'a FractalField> {
+        if fields.is_empty() {
+            return None;
+        }
+        (0..tournament_size.max(1))
+            .map(|_| {
+                let field = &fields[rng.random_range(0..fields.len())];
+                (field, self.score(field))
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+            .map(|(field, _)| field)
+    }
+
+    /// Selects a field with probability proportional to its score (roulette-wheel selection).
+    ///
+    /// Scores are first shifted by the minimum so that all weights are non-negative, which
+    /// means the lowest-scoring field is never chosen unless every score is equal; in that
+    /// case the choice is uniform. Returns `None` if `fields` is empty.
+    pub fn roulette_select<'a>(&self, fields: &'a [FractalField], rng: &mut impl Rng) -> Option<&'a FractalField> {
+        if fields.is_empty() {
+            return None;
+        }
+        let scores: Vec<f32> = fields.iter().map(|f| self.score(f)).collect();
+        let min = scores.iter().cloned().fold(f32::INFINITY, f32::min);
+        let weights: Vec<f32> = scores.iter().map(|s| s - min).collect();
+        let total: f32 = weights.iter().sum();
+        if !(total > 0.0 && total.is_finite()) {
+            return Some(&fields[rng.random_range(0..fields.len())]);
+        }
+
+        let mut spin = rng.random_range(0.0..total);
+        for (field, weight) in fields.iter().zip(&weights) {
+            if spin < *weight {
+                return Some(field);
+            }
+            spin -= weight;
+        }
+        // Rounding can leave `spin` just past the last weight; fall back to the last
+        // field with a non-zero weight.
+        fields.iter().zip(&weights).rev().find(|(_, w)| **w > 0.0).map(|(f, _)| f)
+    }
+}
@@ -0,0 +1,31 @@
+//! Defines `Crossover`, a recombination operation between two parent `FractalField`s.
+//!
+//! Where a `MutationStrategy` perturbs a single field, crossover mixes the genetic
+//! material of two parents, letting independently discovered traits be combined.
+
+use crate::field::FractalField;
+use crate::graphedge::GraphEdge;
+use rand::Rng;
+
+/// Uniform crossover over the complex data of two fields.
+pub struct Crossover;
+
+impl Crossover {
+    /// Recombines two parents into a single child using uniform crossover.
+    ///
+    /// For each edge index, the child's complex `data` is taken from `a` or `b` with equal
+    /// probability, while its geometry always comes from `a`. The child has as many edges as
+    /// `a`; where `b` has no edge at a given index, the data of `a` is kept.
+    pub fn crossover(a: &FractalField, b: &FractalField, rng: &mut impl Rng) -> FractalField {
+        let edges = a
+            .edges
+            .iter()
+            .enumerate()
+            .map(|(i, edge)| match b.edges.get(i) {
+                Some(other) if rng.random_bool(0.5) => GraphEdge { data: other.data, ..*edge },
+                _ => *edge,
+            })
+            .collect();
+        FractalField { edges }
+    }
+}
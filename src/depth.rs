@@ -0,0 +1,51 @@
+//! Defines a `MutationStrategy` that changes the recursive depth structure of a field.
+
+use crate::field::FractalField;
+use crate::graphedge::GraphEdge;
+use crate::traits::MutationStrategy;
+use rand::Rng;
+
+/// A mutation strategy that lets fields deepen (or flatten) over generations.
+///
+/// Each edge's `depth` is shifted by a random amount in `[-max_delta, max_delta]`, clamped
+/// to `[0, u32::MAX]`. In addition, with probability `spawn_probability`, a new child edge is
+/// branched off the endpoint of a randomly chosen edge, one level deeper than its parent
+/// (saturating at `u32::MAX`).
+pub struct DepthMutation {
+    /// The largest change applied to any edge's depth.
+    pub max_delta: u32,
+    /// The probability, in `[0.0, 1.0]`, of spawning a child edge on each mutation.
+    pub spawn_probability: f32,
+}
+
+impl MutationStrategy for DepthMutation {
+    fn mutate(&self, field: &FractalField) -> FractalField {
+        let mut rng = rand::rng();
+        let max_delta = self.max_delta as i64;
+
+        let mut edges: Vec<GraphEdge> = field
+            .edges
+            .iter()
+            .map(|e| {
+                let delta = rng.random_range(-max_delta..=max_delta);
+                GraphEdge {
+                    depth: (e.depth as i64 + delta).clamp(0, u32::MAX as i64) as u32,
+                    ..*e
+                }
+            })
+            .collect();
+
+        if !edges.is_empty() && rng.random_range(0.0..1.0) < self.spawn_probability {
+            let parent = edges[rng.random_range(0..edges.len())];
+            // The child continues from the parent's tip at half its scale, as in a
+            // self-similar branching.
+            edges.push(GraphEdge {
+                origin: parent.endpoint(),
+                depth: parent.depth.saturating_add(1),
+                ..parent.scaled(0.5)
+            });
+        }
+
+        FractalField { edges }
+    }
+}
@@ -0,0 +1,39 @@
+//! Provides diversity metrics for populations of `FractalField`s.
+//!
+//! Evolutionary runs can collapse onto near-identical fields. Measuring how far apart
+//! a population's signatures are helps to detect this premature convergence.
+
+use crate::field::FractalField;
+use crate::signature::feature_distance;
+
+/// The largest population measured exhaustively by `population_diversity`.
+/// Larger populations are measured on an evenly spaced subset of this size.
+pub const DIVERSITY_SAMPLE_LIMIT: usize = 256;
+
+/// Computes the mean pairwise `FractalSignature::distance` across a population.
+///
+/// A population of identical fields has a diversity of `0.0`, as does any population with
+/// fewer than two fields. To bound the quadratic cost, populations larger than
+/// `DIVERSITY_SAMPLE_LIMIT` are measured on an evenly spaced, deterministic subset.
+pub fn population_diversity(fields: &[FractalField]) -> f32 {
+    let step = fields.len().div_ceil(DIVERSITY_SAMPLE_LIMIT).max(1);
+    let features: Vec<[f32; 4]> = fields
+        .iter()
+        .step_by(step)
+        .map(|f| f.signature().features())
+        .collect();
+
+    let n = features.len();
+    if n < 2 {
+        return 0.0;
+    }
+
+    let mut total = 0.0;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            total += feature_distance(&features[i], &features[j]);
+        }
+    }
+    let pairs = (n * (n - 1) / 2) as f32;
+    total / pairs
+}
@@ -3,54 +3,75 @@
 //! This generator is a core component of a genetic or evolutionary algorithm. It produces
 //! new fields by applying a suite of mutations to an existing field.
 
+use crate::crossover::Crossover;
 use crate::field::FractalField;
 use crate::mutation::MutationSuite;
-use crate::traits::Generator;
+use crate::traits::{Generator, MutationStrategy};
+use rand::Rng;
 
-/*
-Note: The commented-out code below represents a potential future refactor
-where the `EvolutionaryGenerator` could be made generic over a `MutationStrategy`.
-This would allow for easily swapping different mutation algorithms.
-
-pub struct EvolutionaryGenerator<S: MutationStrategy> {
-    pub strategy: S,
+/// A generator that creates a population of `FractalField`s by mutating a parent field.
+pub struct EvolutionaryGenerator {
+    /// The suite of mutation operations to apply.
+    pub mutations: MutationSuite,
+    /// The number of new candidates to generate in each `mutate` call.
     pub count: usize,
+    /// The probability, in `[0.0, 1.0]`, that each candidate is first recombined with the
+    /// `secondary_parent` before mutation. Has no effect without a secondary parent.
+    pub crossover_rate: f32,
+    /// An optional second parent for `Crossover` with the field being mutated.
+    pub secondary_parent: Option<FractalField>,
+    /// The number of seeds returned by `generate`. Use `1` for the single identity seed.
+    pub seed_count: usize,
 }
 
-impl<S: MutationStrategy> Generator for EvolutionaryGenerator<S> {
+impl Generator for EvolutionaryGenerator {
+    /// Generates the initial seed population of `seed_count` fields.
+    /// The first seed is the "identity" field; the rest are one round of the
+    /// `MutationSuite` applied to it, so an empty suite yields identical seeds.
     fn generate(&self) -> Vec<FractalField> {
-        // Initial random generation or seed-based
-        vec![FractalField::one()]
+        let identity = FractalField::one();
+        (0..self.seed_count)
+            .map(|i| if i == 0 { identity.clone() } else { self.mutations.mutate(&identity) })
+            .collect()
     }
 
+    /// Creates a new generation of fields by mutating a given parent field.
+    /// It applies the `MutationSuite` `count` times to produce a new population.
+    /// When a `secondary_parent` is set, each candidate is recombined with it at
+    /// `crossover_rate` before being mutated.
     fn mutate(&self, field: &FractalField) -> Vec<FractalField> {
+        let mut rng = rand::rng();
         (0..self.count)
-            .map(|_| self.strategy.mutate(field))
+            .map(|_| match &self.secondary_parent {
+                Some(secondary) if rng.random_range(0.0..1.0) < self.crossover_rate => {
+                    let child = Crossover::crossover(field, secondary, &mut rng);
+                    self.mutations.mutate(&child)
+                }
+                _ => self.mutations.mutate(field),
+            })
             .collect()
     }
 }
-*/
 
-/// A generator that creates a population of `FractalField`s by mutating a parent field.
-pub struct EvolutionaryGenerator {
-    /// The suite of mutation operations to apply.
-    pub mutations: MutationSuite,
+/// A generator that mutates a parent field with a single, concrete `MutationStrategy`.
+///
+/// This is a lighter-weight alternative to `EvolutionaryGenerator` when no weighted
+/// `MutationSuite` is needed.
+pub struct GenericEvolutionaryGenerator<S: MutationStrategy> {
+    /// The mutation strategy to apply.
+    pub strategy: S,
     /// The number of new candidates to generate in each `mutate` call.
     pub count: usize,
 }
 
-impl Generator for EvolutionaryGenerator {
-    /// Generates the initial seed population.
-    /// In this implementation, it's a single "identity" field to kickstart the process.
+impl<S: MutationStrategy> Generator for GenericEvolutionaryGenerator<S> {
+    /// Generates the initial seed population: a single "identity" field.
     fn generate(&self) -> Vec<FractalField> {
         vec![FractalField::one()]
     }
 
-    /// Creates a new generation of fields by mutating a given parent field.
-    /// It applies the `MutationSuite` `count` times to produce a new population.
+    /// Creates a new generation by applying the strategy `count` times to the parent.
     fn mutate(&self, field: &FractalField) -> Vec<FractalField> {
-        (0..self.count)
-            .map(|_| self.mutations.mutate(field))
-            .collect()
+        (0..self.count).map(|_| self.strategy.mutate(field)).collect()
     }
-}
\ No newline at end of file
+}
@@ -5,11 +5,20 @@
 //! where each `GraphEdge` is a basis vector. It supports fundamental vector
 //! operations like addition, negation, and scalar multiplication through operator overloading.
 
+use crate::filters::FieldFilter;
 use crate::graphedge::GraphEdge;
+use crate::resonance::{classify_phasor, ResonanceLaw};
 use crate::signature::FractalSignature;
+use crate::traits::VectorSpace;
 use crate::vec3::Vec3;
 use num_complex::Complex;
 use rand::Rng;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+/// The grid spacing to which edge data is rounded by `FractalField::content_hash`.
+pub const CONTENT_HASH_RESOLUTION: f32 = 1e-4;
 
 /// A collection of `GraphEdge`s that represents a coherent state or pattern.
 #[derive(Clone, Debug, PartialEq)]
@@ -53,6 +62,278 @@ impl FractalField {
         }
     }
 
+    /// Creates a field of `n` edges, the `i`-th of which is `f(i)`.
+    pub fn from_fn(n: usize, f: impl Fn(usize) -> GraphEdge) -> Self {
+        FractalField { edges: (0..n).map(f).collect() }
+    }
+
+    /// Creates a field of `n` unit-length edges laid out along a spiral in the XY plane,
+    /// with the `i`-th edge's data set to `amp_fn(i)` and `phase_fn(i)` in polar form.
+    ///
+    /// Edge `i` sits at angle `τ·i/n` and radius `(i + 1)/n` from the world origin, pointing
+    /// along the tangent of the circle through it, so one full turn spans the whole field.
+    pub fn from_polar_fn(n: usize, amp_fn: impl Fn(usize) -> f32, phase_fn: impl Fn(usize) -> f32) -> Self {
+        Self::from_fn(n, |i| {
+            let angle = std::f32::consts::TAU * i as f32 / n as f32;
+            let radius = (i + 1) as f32 / n as f32;
+            let (sin, cos) = angle.sin_cos();
+            GraphEdge {
+                origin: Vec3 { x: radius * cos, y: radius * sin, z: 0.0 },
+                direction: Vec3 { x: -sin, y: cos, z: 0.0 },
+                length: 1.0,
+                depth: 0,
+                data: Complex::from_polar(amp_fn(i), phase_fn(i)),
+            }
+        })
+    }
+
+    /// Returns the number of edges in the field.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns `true` if the field contains no edges.
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Returns an iterator over references to the field's edges.
+    pub fn iter(&self) -> std::slice::Iter<'_, GraphEdge> {
+        self.edges.iter()
+    }
+
+    /// Appends a single edge to the end of the field.
+    pub fn push_edge(&mut self, edge: GraphEdge) {
+        self.edges.push(edge);
+    }
+
+    /// Concatenates the edges of two fields into a new, composite field.
+    ///
+    /// Unlike `Add`, which combines the data of edges at the same index, this preserves
+    /// every edge from both inputs: those of `self` first, followed by those of `other`.
+    pub fn concat(&self, other: &Self) -> FractalField {
+        let mut edges = Vec::with_capacity(self.edges.len() + other.edges.len());
+        edges.extend_from_slice(&self.edges);
+        edges.extend_from_slice(&other.edges);
+        FractalField { edges }
+    }
+
+    /// Returns a new field without the edges whose `data.norm()` is below `threshold`.
+    ///
+    /// Pruning changes the edge count and shifts the remaining edges' indices, so the
+    /// result no longer lines up pointwise with the original under `Add`.
+    pub fn prune(&self, threshold: f32) -> FractalField {
+        let mut pruned = self.clone();
+        pruned.prune_in_place(threshold);
+        pruned
+    }
+
+    /// Removes, in place, the edges whose `data.norm()` is below `threshold`.
+    /// See `prune`.
+    pub fn prune_in_place(&mut self, threshold: f32) {
+        self.edges.retain(|e| e.data.norm() >= threshold);
+    }
+
+    /// Convolves two fields edge by edge, the field-level analogue of `FractalEdge::convolve`.
+    ///
+    /// Each pair of edges has its complex data multiplied and its depths added, saturating
+    /// at `u32::MAX`. Edges are paired by index and the result is truncated to the shorter
+    /// field. Geometry (origin, direction and length) is taken from `self`; `other`
+    /// contributes only its signal.
+    pub fn convolve(&self, other: &Self) -> FractalField {
+        let edges = self
+            .edges
+            .iter()
+            .zip(&other.edges)
+            .map(|(a, b)| GraphEdge {
+                data: a.data * b.data,
+                depth: a.depth.saturating_add(b.depth),
+                ..*a
+            })
+            .collect();
+        FractalField { edges }
+    }
+
+    /// Measures how phase-aligned the field's edges are.
+    ///
+    /// This is the mean resultant length of the edges' unit phasors,
+    /// `|Σ exp(i·phase_k)| / n`, in `[0, 1]`: `1.0` means every edge shares the same
+    /// phase, while phases spread evenly around the circle give `0.0`. An empty field
+    /// has no phase and scores `0.0`.
+    pub fn phase_coherence(&self) -> f32 {
+        if self.edges.is_empty() {
+            return 0.0;
+        }
+        let resultant: Complex<f32> = self
+            .edges
+            .iter()
+            .map(|e| Complex::from_polar(1.0, e.data.arg()))
+            .sum();
+        resultant.norm() / self.edges.len() as f32
+    }
+
+    /// Returns the most common `ResonanceLaw` among the field's edges.
+    ///
+    /// Each edge is classified from its amplitude and phase with the same thresholds as a
+    /// `FractalEdge`. Ties are broken in favour of the law carrying the greater total
+    /// amplitude. An empty field yields `ResonanceLaw::Null`.
+    pub fn dominant_law(&self) -> ResonanceLaw {
+        // (law, edge count, total amplitude)
+        let mut tallies: Vec<(ResonanceLaw, usize, f32)> = Vec::new();
+        for edge in &self.edges {
+            let (amp, phase) = edge.data.to_polar();
+            let law = classify_phasor(amp, phase);
+            match tallies.iter_mut().find(|(l, _, _)| *l == law) {
+                Some((_, count, total)) => {
+                    *count += 1;
+                    *total += amp;
+                }
+                None => tallies.push((law, 1, amp)),
+            }
+        }
+
+        tallies
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then(a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal)))
+            .map_or(ResonanceLaw::Null, |(law, _, _)| law)
+    }
+
+    /// Returns the field's total energy, `Σ |data|²` over its edges.
+    pub fn energy(&self) -> f32 {
+        self.edges.iter().map(|e| e.data.norm_sqr()).sum()
+    }
+
+    /// Returns the average energy per edge, or `0.0` for an empty field.
+    pub fn power(&self) -> f32 {
+        if self.edges.is_empty() {
+            return 0.0;
+        }
+        self.energy() / self.edges.len() as f32
+    }
+
+    /// Returns a copy of the field with every edge's origin moved by `offset`.
+    pub fn translate(&self, offset: Vec3) -> FractalField {
+        self.map_geometry(|e| GraphEdge { origin: e.origin + offset, ..*e })
+    }
+
+    /// Returns a copy of the field scaled by `factor` about the world origin: origins and
+    /// lengths are multiplied by `factor`, while directions are unchanged.
+    pub fn scale(&self, factor: f32) -> FractalField {
+        self.map_geometry(|e| GraphEdge { origin: e.origin * factor, length: e.length * factor, ..*e })
+    }
+
+    /// Returns a copy of the field rotated by `radians` about the world Z axis,
+    /// turning both origins and directions (counter-clockwise when viewed from +Z).
+    pub fn rotate_z(&self, radians: f32) -> FractalField {
+        let rotate = |v: Vec3| v.rotate_around(Vec3::Z, radians);
+        self.map_geometry(|e| GraphEdge { origin: rotate(e.origin), direction: rotate(e.direction), ..*e })
+    }
+
+    /// Applies a geometric transform to each edge. The complex data is carried over as is.
+    fn map_geometry<F: Fn(&GraphEdge) -> GraphEdge>(&self, f: F) -> FractalField {
+        FractalField { edges: self.edges.iter().map(f).collect() }
+    }
+
+    /// Linearly interpolates between this field and `other`, edge by edge.
+    ///
+    /// Origins, lengths and complex data are blended as `self * (1 - t) + other * t`, while
+    /// directions use `Vec3::slerp` so that they stay unit length throughout (a zero
+    /// direction is blended linearly instead). `t = 0`
+    /// reproduces `self` and `t = 1` reproduces `other`, up to rounding. The integer `depth`
+    /// switches from `self`'s to `other`'s at `t = 0.5`. Edges are paired by index and the
+    /// result is truncated to the shorter of the two fields.
+    pub fn lerp(&self, other: &Self, t: f32) -> FractalField {
+        let mix = |a: f32, b: f32| a * (1.0 - t) + b * t;
+        let mix_vec = |a: Vec3, b: Vec3| Vec3 { x: mix(a.x, b.x), y: mix(a.y, b.y), z: mix(a.z, b.z) };
+        let edges = self
+            .edges
+            .iter()
+            .zip(&other.edges)
+            .map(|(a, b)| GraphEdge {
+                origin: mix_vec(a.origin, b.origin),
+                direction: a.direction.slerp(b.direction, t),
+                length: mix(a.length, b.length),
+                depth: if t < 0.5 { a.depth } else { b.depth },
+                data: Complex::new(mix(a.data.re, b.data.re), mix(a.data.im, b.data.im)),
+            })
+            .collect();
+        FractalField { edges }
+    }
+
+    /// Returns the mean of the edges' endpoints, or `Vec3::ZERO` for an empty field.
+    pub fn centroid(&self) -> Vec3 {
+        if self.edges.is_empty() {
+            return Vec3::ZERO;
+        }
+        let sum = self.edges.iter().fold(Vec3::ZERO, |acc, e| acc + e.endpoint());
+        sum * (1.0 / self.edges.len() as f32)
+    }
+
+    /// Returns the `(min, max)` corners of the axis-aligned box enclosing every edge's
+    /// origin and endpoint.
+    ///
+    /// An empty field yields an inverted box, with `min` at `+∞` and `max` at `-∞` on every
+    /// axis, so that it acts as the identity when merged with other boxes.
+    pub fn bounding_box(&self) -> (Vec3, Vec3) {
+        let inf = f32::INFINITY;
+        let mut min = Vec3 { x: inf, y: inf, z: inf };
+        let mut max = Vec3 { x: -inf, y: -inf, z: -inf };
+        for point in self.edges.iter().flat_map(|e| [e.origin, e.endpoint()]) {
+            min = Vec3 { x: min.x.min(point.x), y: min.y.min(point.y), z: min.z.min(point.z) };
+            max = Vec3 { x: max.x.max(point.x), y: max.y.max(point.y), z: max.z.max(point.z) };
+        }
+        (min, max)
+    }
+
+    /// Computes a hash of the field's content, for use as a cache key.
+    ///
+    /// Edge geometry and depth are hashed exactly, while the complex data is first
+    /// quantized to a grid of `CONTENT_HASH_RESOLUTION`, so float noise smaller than the
+    /// grid usually leaves the hash unchanged. The hash uses the standard library's
+    /// `DefaultHasher`, so it is stable within a build but should not be persisted.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        // Adding 0.0 folds -0.0 into 0.0 so that equal values hash equally.
+        let bits = |x: f32| (x + 0.0).to_bits();
+        let quantize = |x: f32| (x / CONTENT_HASH_RESOLUTION).round() as i64;
+        self.edges.len().hash(&mut hasher);
+        for e in &self.edges {
+            for v in [e.origin, e.direction] {
+                [bits(v.x), bits(v.y), bits(v.z)].hash(&mut hasher);
+            }
+            bits(e.length).hash(&mut hasher);
+            e.depth.hash(&mut hasher);
+            [quantize(e.data.re), quantize(e.data.im)].hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Checks whether two fields are equal up to floating-point error.
+    ///
+    /// The fields must have the same number of edges with identical depths, and each pair
+    /// of edges must agree within `epsilon` in every component of its origin and direction,
+    /// in its length, and in the distance between its complex data.
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.edges.len() == other.edges.len()
+            && self.edges.iter().zip(&other.edges).all(|(a, b)| {
+                a.depth == b.depth
+                    && a.origin.approx_eq(&b.origin, epsilon)
+                    && a.direction.approx_eq(&b.direction, epsilon)
+                    && (a.length - b.length).abs() <= epsilon
+                    && (a.data - b.data).norm() <= epsilon
+            })
+    }
+
+    /// Checks whether the field passes `filter`.
+    pub fn passes(&self, filter: &dyn FieldFilter) -> bool {
+        filter.passes(self)
+    }
+
+    /// Returns clones of the `fields` that pass `filter`, in their original order.
+    pub fn filter_fields(fields: &[FractalField], filter: &dyn FieldFilter) -> Vec<FractalField> {
+        fields.iter().filter(|f| filter.passes(f)).cloned().collect()
+    }
+
     /// Checks if the field is effectively zero by testing if all edge data has a negligible norm.
     pub fn is_zero(&self) -> bool {
         self.edges.iter().all(|e| e.data.norm() < 1e-6)
@@ -63,26 +344,41 @@ impl FractalField {
     /// The signature is a condensed summary of the field's properties, such as total amplitude,
     /// average phase, and complexity, which can be used for classification or analysis.
     pub fn signature(&self) -> FractalSignature {
-        if self.edges.is_empty() {
-            return FractalSignature {
-                total_amplitude: 0.0,
-                average_phase: 0.0,
-                entropy: 0.0,
-                edge_count: 0,
-                depth_range: (u32::MAX, 0),
-            };
+        Self::signature_of(&self.edges)
+    }
+
+    /// Computes a `FractalSignature` over only the edges with `depth <= max_depth`.
+    ///
+    /// This summarizes the field's coarse structure, ignoring finer fractal levels. If no
+    /// edge is shallow enough, the result matches the signature of an empty field.
+    pub fn signature_at_depth(&self, max_depth: u32) -> FractalSignature {
+        Self::signature_of(self.edges.iter().filter(|e| e.depth <= max_depth))
+    }
+
+    /// Splits the field into one sub-field per `depth` level, keyed by depth in ascending
+    /// order. Edges keep their relative order within each sub-field.
+    pub fn by_depth(&self) -> BTreeMap<u32, FractalField> {
+        let mut levels: BTreeMap<u32, FractalField> = BTreeMap::new();
+        for edge in &self.edges {
+            levels.entry(edge.depth).or_insert_with(FractalField::zero).push_edge(*edge);
         }
+        levels
+    }
 
+    /// Computes the signature of an arbitrary set of edges.
+    fn signature_of<'a>(edges: impl IntoIterator<Item = &'a GraphEdge>) -> FractalSignature {
+        let mut count = 0;
         let mut total_amp = 0.0;
         let mut total_phase = 0.0;
         let mut entropy = 0.0; // A crude proxy for complexity.
         let mut min_depth = u32::MAX;
         let mut max_depth = 0;
 
-        for edge in &self.edges {
+        for edge in edges {
             let amp = edge.data.norm();
             let phase = edge.data.arg();
 
+            count += 1;
             total_amp += amp;
             total_phase += phase;
             entropy += amp * phase.abs();
@@ -91,17 +387,58 @@ impl FractalField {
             max_depth = max_depth.max(edge.depth);
         }
 
-        let count = self.edges.len() as f32;
+        if count == 0 {
+            return FractalSignature {
+                total_amplitude: 0.0,
+                average_phase: 0.0,
+                entropy: 0.0,
+                edge_count: 0,
+                depth_range: (u32::MAX, 0),
+            };
+        }
+
         FractalSignature {
             total_amplitude: total_amp,
-            average_phase: total_phase / count,
+            average_phase: total_phase / count as f32,
             entropy,
-            edge_count: self.edges.len(),
+            edge_count: count,
             depth_range: (min_depth, max_depth),
         }
     }
 }
 
+// --- Collection Traits ---
+
+impl IntoIterator for FractalField {
+    type Item = GraphEdge;
+    type IntoIter = std::vec::IntoIter<GraphEdge>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FractalField {
+    type Item = &'a GraphEdge;
+    type IntoIter = std::slice::Iter<'a, GraphEdge>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edges.iter()
+    }
+}
+
+/// Provides direct indexed access to the field's edges.
+///
+/// # Panics
+/// Panics if `index` is out of bounds.
+impl std::ops::Index<usize> for FractalField {
+    type Output = GraphEdge;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.edges[index]
+    }
+}
+
 // --- Operator Overloading ---
 
 /// Implements the unary negation operator (`-`).
@@ -133,14 +470,48 @@ impl std::ops::Mul<Complex<f32>> for FractalField {
     }
 }
 
+/// Implements the multiplication operator (`*`) for scaling by a real number.
+impl std::ops::Mul<f32> for FractalField {
+    type Output = Self;
+
+    fn mul(self, scalar: f32) -> Self::Output {
+        let edges = self
+            .edges
+            .into_iter()
+            .map(|e| GraphEdge { data: e.data * scalar, ..e })
+            .collect();
+        FractalField { edges }
+    }
+}
+
+/// Implements scalar-first multiplication (`scalar * field`), matching `field * scalar`.
+impl std::ops::Mul<FractalField> for Complex<f32> {
+    type Output = FractalField;
+
+    fn mul(self, field: FractalField) -> FractalField {
+        field * self
+    }
+}
+
+/// Implements real scalar-first multiplication (`scalar * field`), matching `field * scalar`.
+impl std::ops::Mul<FractalField> for f32 {
+    type Output = FractalField;
+
+    fn mul(self, field: FractalField) -> FractalField {
+        field * self
+    }
+}
+
 /// Implements the addition operator (`+`).
-/// This performs pointwise addition of the complex data of two fields.
-/// It assumes that both fields have the same number and ordering of edges.
+/// This performs pointwise addition of the complex data of two fields, pairing edges by
+/// index. If one field has more edges, its unpaired edges are kept unchanged, as if added
+/// to zero, so the empty `FractalField::zero()` is the additive identity.
 impl std::ops::Add for FractalField {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let edges = self
+        let paired = self.edges.len().min(rhs.edges.len());
+        let mut edges: Vec<GraphEdge> = self
             .edges
             .iter()
             .zip(rhs.edges.iter())
@@ -153,7 +524,38 @@ impl std::ops::Add for FractalField {
                 data: a.data + b.data,
             })
             .collect();
+        // At most one of these tails is non-empty.
+        edges.extend(self.edges.into_iter().skip(paired));
+        edges.extend(rhs.edges.into_iter().skip(paired));
+
+        FractalField { edges }
+    }
+}
+
+/// Implements the subtraction operator (`-`).
+/// This performs pointwise subtraction of the complex data of two fields, with the same
+/// conventions as `Add`: edges are paired by index, geometry is taken from `self`, and
+/// unpaired edges are subtracted from zero (so those of `rhs` are negated).
+impl std::ops::Sub for FractalField {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let paired = self.edges.len().min(rhs.edges.len());
+        let mut edges: Vec<GraphEdge> = self
+            .edges
+            .iter()
+            .zip(rhs.edges.iter())
+            .map(|(a, b)| GraphEdge { data: a.data - b.data, ..*a })
+            .collect();
+        edges.extend(self.edges.into_iter().skip(paired));
+        edges.extend(rhs.edges.into_iter().skip(paired).map(|e| GraphEdge { data: -e.data, ..e }));
 
         FractalField { edges }
     }
-}
\ No newline at end of file
+}
+
+impl VectorSpace for FractalField {
+    fn zero() -> Self {
+        FractalField::zero()
+    }
+}
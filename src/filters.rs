@@ -4,9 +4,11 @@
 //! for selecting fractals based on different criteria like resonance laws, scores, tags,
 //! and metadata. It also includes logic for composing filters together.
 
-use crate::resonance::{ResonanceFilter, ResonanceLaw, SemanticUnit};
-use crate::traits::{Fractal, FractalCollection};
+use crate::field::FractalField;
+use crate::resonance::{Resonance, ResonanceFilter, ResonanceLaw, SemanticUnit};
+use crate::traits::{Fractal, FractalCollection, Operation};
 use std::any::Any;
+use std::collections::HashMap;
 
 // --- Simple Filters ---
 
@@ -65,7 +67,8 @@ impl ResonanceFilter for PredicateFilter {
 
     /// `passes` cannot use the predicate, as it operates on `&dyn Fractal`, not `SemanticUnit`.
     /// This implementation defaults to `true`, making it a no-op at the `Fractal` level.
-    /// Its filtering logic is only effective when used via the `apply` method.
+    /// Its filtering logic is only effective when used via the `apply` method; use
+    /// `FractalPredicateFilter` to filter at the `Fractal` level instead.
     fn passes(&self, _fractal: &dyn Fractal) -> bool {
         true
     }
@@ -76,6 +79,29 @@ impl ResonanceFilter for PredicateFilter {
     }
 }
 
+/// A thread-safe closure testing a `Fractal` trait object, as used by `FractalPredicateFilter`.
+pub type FractalPredicate = Box<dyn Fn(&dyn Fractal) -> bool + Send + Sync>;
+
+/// A flexible filter that uses a provided closure to test `Fractal` trait objects.
+///
+/// Unlike `PredicateFilter`, the closure is evaluated by `passes`, so this works with
+/// `ResonantFractalCollection::filter`.
+pub struct FractalPredicateFilter {
+    pub predicate: FractalPredicate,
+}
+
+impl ResonanceFilter for FractalPredicateFilter {
+    fn as_any(&self) -> &dyn Any { self }
+
+    fn passes(&self, fractal: &dyn Fractal) -> bool {
+        (self.predicate)(fractal)
+    }
+
+    fn apply(&self, units: &[SemanticUnit]) -> Vec<SemanticUnit> {
+        units.iter().filter(|unit| self.passes(&*unit.fractal)).cloned().collect()
+    }
+}
+
 /// Filters based on whether a fractal has all of a given set of tags.
 pub struct TagMatchFilter {
     pub required_tags: Vec<String>,
@@ -95,6 +121,40 @@ impl ResonanceFilter for TagMatchFilter {
     }
 }
 
+/// Filters based on whether a fractal has at least one of a given set of tags.
+pub struct AnyTagFilter {
+    pub tags: Vec<String>,
+}
+
+impl ResonanceFilter for AnyTagFilter {
+    fn as_any(&self) -> &dyn Any { self }
+
+    fn passes(&self, fractal: &dyn Fractal) -> bool {
+        self.tags.iter().any(|tag| fractal.tags().contains(tag))
+    }
+
+    fn apply(&self, units: &[SemanticUnit]) -> Vec<SemanticUnit> {
+        units.iter().filter(|unit| self.passes(&*unit.fractal)).cloned().collect()
+    }
+}
+
+/// Filters based on whether any of a fractal's tags starts with a given prefix.
+pub struct TagPrefixFilter {
+    pub prefix: String,
+}
+
+impl ResonanceFilter for TagPrefixFilter {
+    fn as_any(&self) -> &dyn Any { self }
+
+    fn passes(&self, fractal: &dyn Fractal) -> bool {
+        fractal.tags().has_prefix(&self.prefix)
+    }
+
+    fn apply(&self, units: &[SemanticUnit]) -> Vec<SemanticUnit> {
+        units.iter().filter(|unit| self.passes(&*unit.fractal)).cloned().collect()
+    }
+}
+
 /// Filters based on the `domain` field in a fractal's metadata.
 pub struct DomainFilter {
     pub domain: String,
@@ -112,6 +172,31 @@ impl ResonanceFilter for DomainFilter {
     }
 }
 
+/// Filters based on a substring of the `description` in a fractal's metadata.
+/// Fractals without a description never pass.
+pub struct DescriptionFilter {
+    pub needle: String,
+    pub case_insensitive: bool,
+}
+
+impl ResonanceFilter for DescriptionFilter {
+    fn as_any(&self) -> &dyn Any { self }
+
+    fn passes(&self, fractal: &dyn Fractal) -> bool {
+        match &fractal.metadata().description {
+            Some(description) if self.case_insensitive => {
+                description.to_lowercase().contains(&self.needle.to_lowercase())
+            }
+            Some(description) => description.contains(&self.needle),
+            None => false,
+        }
+    }
+
+    fn apply(&self, units: &[SemanticUnit]) -> Vec<SemanticUnit> {
+        units.iter().filter(|unit| self.passes(&*unit.fractal)).cloned().collect()
+    }
+}
+
 // --- Composite Filters (Two Implementations) ---
 
 // Note: This file contains two different implementations for composing filters:
@@ -212,6 +297,50 @@ impl ResonanceFilter for CompositeFilter {
     }
 }
 
+// --- FractalField Filters ---
+
+/// A filter that tests `FractalField`s directly.
+///
+/// This parallels `ResonanceFilter`, which only works on `Fractal` trait objects, so that
+/// the fields produced by generators can be filtered without wrapping them.
+pub trait FieldFilter {
+    fn passes(&self, field: &FractalField) -> bool;
+}
+
+/// Filters fields based on a minimum `Resonance::resonance_score`.
+pub struct ScoreFieldFilter {
+    pub min_score: f64,
+}
+
+impl FieldFilter for ScoreFieldFilter {
+    fn passes(&self, field: &FractalField) -> bool {
+        field.resonance_score() >= self.min_score
+    }
+}
+
+/// Filters fields whose `dominant_law` is one of the allowed `ResonanceLaw`s.
+pub struct LawFieldFilter {
+    pub allowed: Vec<ResonanceLaw>,
+}
+
+impl FieldFilter for LawFieldFilter {
+    fn passes(&self, field: &FractalField) -> bool {
+        self.allowed.contains(&field.dominant_law())
+    }
+}
+
+/// Filters fields whose signature entropy lies within `[min, max]`, inclusive.
+pub struct EntropyRangeFilter {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl FieldFilter for EntropyRangeFilter {
+    fn passes(&self, field: &FractalField) -> bool {
+        (self.min..=self.max).contains(&field.signature().entropy)
+    }
+}
+
 // --- Filtering Infrastructure ---
 
 /// A record of which fractals passed or failed a named filter. Useful for debugging.
@@ -222,6 +351,36 @@ pub struct FilterTrace {
     pub failed: Vec<usize>, // Indices that failed
 }
 
+impl FilterTrace {
+    /// Returns the number of fractals the filter was run over.
+    pub fn total(&self) -> usize {
+        self.passed.len() + self.failed.len()
+    }
+
+    /// Returns the fraction of fractals that passed, in `[0.0, 1.0]`, or `0.0` for an
+    /// empty trace.
+    pub fn pass_rate(&self) -> f32 {
+        if self.total() == 0 {
+            return 0.0;
+        }
+        self.passed.len() as f32 / self.total() as f32
+    }
+}
+
+impl std::fmt::Display for FilterTrace {
+    /// Formats the trace as `"<name>: X/Y passed (Z%)"`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {}/{} passed ({:.1}%)",
+            self.filter_name,
+            self.passed.len(),
+            self.total(),
+            self.pass_rate() * 100.0
+        )
+    }
+}
+
 /// A container that augments a `FractalCollection` with pre-calculated resonance data.
 /// This avoids re-computing scores and laws repeatedly during filtering operations.
 #[derive(Debug, Clone)]
@@ -267,6 +426,119 @@ impl ResonantFractalCollection {
     pub fn reevaluate(&mut self) {
         *self = Self::new(self.collection.clone());
     }
+
+    /// Re-evaluates the resonance data of the member at `index` only, updating
+    /// `average_resonance` incrementally instead of recomputing it from every member.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn reevaluate_member(&mut self, index: usize) {
+        let fractal = &self.collection.members[index].fractal;
+        let new_score = fractal.resonance_score();
+        let old_score = std::mem::replace(&mut self.resonance_scores[index], new_score);
+        self.resonance_laws[index] = fractal.resonance_law();
+        self.average_resonance += (new_score - old_score) / self.resonance_scores.len() as f64;
+    }
+}
+
+/// Summary statistics for `ResonantFractalCollection`, computed from the cached data.
+impl ResonantFractalCollection {
+    /// Counts how many members follow each `ResonanceLaw`.
+    pub fn law_counts(&self) -> HashMap<ResonanceLaw, usize> {
+        let mut counts = HashMap::new();
+        for law in &self.resonance_laws {
+            *counts.entry(*law).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Aggregates the cached resonance scores according to each member's CSG `Operation`.
+    ///
+    /// `Union` scores are added and `Difference` scores subtracted, then the total is
+    /// multiplied by every `Intersection` score, so the result is
+    /// `(Σ union − Σ difference) × Π intersection`. A collection with only `Intersection`
+    /// members has no sum to scale, so it scores `Π intersection` rather than always `0.0`.
+    /// An empty collection scores `0.0`.
+    pub fn operation_weighted_resonance(&self) -> f64 {
+        let mut sum = None;
+        let mut product = None;
+        for (member, score) in self.collection.members.iter().zip(&self.resonance_scores) {
+            match member.operation {
+                Operation::Union => *sum.get_or_insert(0.0) += score,
+                Operation::Difference => *sum.get_or_insert(0.0) -= score,
+                Operation::Intersection => *product.get_or_insert(1.0) *= score,
+            }
+        }
+        match (sum, product) {
+            (Some(sum), product) => sum * product.unwrap_or(1.0),
+            (None, Some(product)) => product,
+            (None, None) => 0.0,
+        }
+    }
+
+    /// Bins the cached resonance scores into `bins` equal-width bins spanning the lowest
+    /// to the highest score.
+    ///
+    /// At least one bin is always created, even if `bins` is zero. Non-finite scores are
+    /// left out, and if all remaining scores are equal they share the first bin.
+    pub fn score_histogram(&self, bins: usize) -> Vec<usize> {
+        let bins = bins.max(1);
+        let scores: Vec<f64> = self.resonance_scores.iter().copied().filter(|s| s.is_finite()).collect();
+        let min = scores.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mut counts = vec![0; bins];
+
+        let width = (max - min) / bins as f64;
+        for score in scores {
+            let bin = if width > 0.0 { ((score - min) / width) as usize } else { 0 };
+            // The maximum score lands exactly on the upper edge; keep it in the last bin.
+            counts[bin.min(bins - 1)] += 1;
+        }
+        counts
+    }
+}
+
+/// Ranking methods for `ResonantFractalCollection`, using the cached resonance scores.
+impl ResonantFractalCollection {
+    /// Returns a new `FractalCollection` with the members ordered by their cached resonance
+    /// scores, highest first if `descending`.
+    ///
+    /// The sort is stable, so members with equal scores keep their relative order. Call
+    /// `ResonantFractalCollection::new` on the result to get scores aligned with it.
+    pub fn sorted_by_score(&self, descending: bool) -> FractalCollection {
+        let mut order: Vec<usize> = (0..self.collection.members.len()).collect();
+        order.sort_by(|&a, &b| {
+            let ordering = self.resonance_scores[a].total_cmp(&self.resonance_scores[b]);
+            if descending { ordering.reverse() } else { ordering }
+        });
+        self.members_at(&order)
+    }
+
+    /// Returns the `k` members with the highest cached resonance scores, highest first.
+    ///
+    /// The top `k` are found with a partial selection before only they are sorted, so this
+    /// is cheaper than `sorted_by_score` for small `k`. If `k` exceeds the member count,
+    /// every member is returned.
+    pub fn top_k(&self, k: usize) -> FractalCollection {
+        let k = k.min(self.collection.members.len());
+        let scores = &self.resonance_scores;
+        let by_score_desc = |a: &usize, b: &usize| scores[*b].total_cmp(&scores[*a]);
+
+        let mut order: Vec<usize> = (0..self.collection.members.len()).collect();
+        if k > 0 && k < order.len() {
+            order.select_nth_unstable_by(k - 1, by_score_desc);
+        }
+        order.truncate(k);
+        order.sort_by(by_score_desc);
+        self.members_at(&order)
+    }
+
+    /// Clones the members at `indices`, in that order, into a new collection.
+    fn members_at(&self, indices: &[usize]) -> FractalCollection {
+        let members = indices.iter().map(|&i| self.collection.members[i].clone()).collect();
+        FractalCollection { members }
+    }
 }
 
 /// Filtering methods for `ResonantFractalCollection`.
@@ -305,4 +577,28 @@ impl ResonantFractalCollection {
             failed,
         }
     }
-}
\ No newline at end of file
+
+    /// Applies `filters` in sequence, tracing each stage against the members that survived
+    /// the stages before it.
+    ///
+    /// Returns the final surviving collection and one `FilterTrace` per stage, named after
+    /// its `&str` label. Indices in every trace refer to positions in the original
+    /// collection, so a member can be followed through the whole pipeline.
+    pub fn trace_pipeline(
+        &self,
+        filters: &[(&str, &dyn ResonanceFilter)],
+    ) -> (FractalCollection, Vec<FilterTrace>) {
+        let mut surviving: Vec<usize> = (0..self.collection.members.len()).collect();
+        let mut traces = Vec::with_capacity(filters.len());
+
+        for (name, filter) in filters {
+            let (passed, failed): (Vec<usize>, Vec<usize>) = surviving
+                .iter()
+                .partition(|&&i| filter.passes(&self.collection.members[i].fractal));
+            surviving = passed.clone();
+            traces.push(FilterTrace { filter_name: name.to_string(), passed, failed });
+        }
+
+        (self.members_at(&surviving), traces)
+    }
+}
@@ -27,9 +27,18 @@ impl FractalEdge {
     /// and phases, wrapping the location by the global `MODULUS`. It's analogous
     /// to frequency-domain multiplication in signal processing.
     pub fn convolve(&self, other: &Self) -> Self {
+        self.convolve_mod(other, MODULUS)
+    }
+
+    /// Performs a convolution of two edges, wrapping the location by `modulus`
+    /// instead of the global `MODULUS`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    pub fn convolve_mod(&self, other: &Self, modulus: usize) -> Self {
         FractalEdge {
             amplitude: self.amplitude * other.amplitude,
-            location: (self.location + other.location) % MODULUS,
+            location: (self.location + other.location) % modulus,
             phase: self.phase + other.phase, // Note: phase is not wrapped, may accumulate.
         }
     }
@@ -0,0 +1,64 @@
+//! Defines a `MutationStrategy` that applies stochastic jitter to edge geometry.
+
+use crate::field::FractalField;
+use crate::graphedge::GraphEdge;
+use crate::traits::MutationStrategy;
+use crate::vec3::Vec3;
+use rand::Rng;
+
+/// The smallest length an edge may be jittered down to, keeping lengths positive.
+const MIN_LENGTH: f32 = 1e-6;
+
+/// A mutation strategy that perturbs the spatial structure of each edge.
+///
+/// The `origin` is shifted, the `length` is nudged (but kept positive), and the
+/// `direction` is tilted and re-normalized. The complex `data` is left untouched, which
+/// makes this the geometric counterpart to `StochasticAmplitudePhase`.
+pub struct GeometricJitter {
+    /// The maximum random shift applied to each component of the origin.
+    pub position_jitter: f32,
+    /// The maximum random change applied to the length.
+    pub length_jitter: f32,
+    /// The maximum random tilt applied to each component of the direction before re-normalizing.
+    pub rotate_jitter: f32,
+}
+
+impl MutationStrategy for GeometricJitter {
+    fn mutate(&self, field: &FractalField) -> FractalField {
+        let mut rng = rand::rng();
+
+        let edges = field
+            .edges
+            .iter()
+            .map(|e| {
+                let shift = random_offset(&mut rng, self.position_jitter);
+                let tilt = random_offset(&mut rng, self.rotate_jitter);
+                let tilted = (e.direction + tilt).normalize();
+
+                GraphEdge {
+                    origin: e.origin + shift,
+                    // A degenerate tilt (cancelling the direction) keeps the original direction.
+                    direction: if tilted == Vec3::ZERO { e.direction } else { tilted },
+                    length: (e.length + jitter(&mut rng, self.length_jitter)).max(MIN_LENGTH),
+                    ..*e
+                }
+            })
+            .collect();
+
+        FractalField { edges }
+    }
+}
+
+/// Draws a value uniformly from `[-amount, amount)`, or zero if `amount` is not positive.
+fn jitter(rng: &mut impl Rng, amount: f32) -> f32 {
+    if amount > 0.0 { rng.random_range(-amount..amount) } else { 0.0 }
+}
+
+/// Draws a vector whose components are each jittered by up to `amount`.
+fn random_offset(rng: &mut impl Rng, amount: f32) -> Vec3 {
+    Vec3 {
+        x: jitter(rng, amount),
+        y: jitter(rng, amount),
+        z: jitter(rng, amount),
+    }
+}
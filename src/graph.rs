@@ -8,7 +8,7 @@
 
 use num_complex::Complex;
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Write};
 use thiserror::Error;
 
 // --- Core Data Structures ---
@@ -53,7 +53,7 @@ pub enum EdgeType {
 /// A directed graph using an adjacency list representation.
 ///
 /// The generic type `T` represents the data stored in each node's payload.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FractalGraph<T> {
     /// Stores the nodes of the graph, mapped by their unique `NodeId`.
     nodes: HashMap<NodeId, Node<T>>,
@@ -74,6 +74,18 @@ pub enum GraphError {
     DuplicateEdge(NodeId, NodeId, EdgeType),
 }
 
+/// The bookkeeping for one run of Tarjan's algorithm.
+#[derive(Default)]
+struct TarjanState {
+    /// The order in which each node was first visited.
+    index: HashMap<NodeId, usize>,
+    /// The lowest index reachable from each node through its DFS subtree.
+    low_link: HashMap<NodeId, usize>,
+    stack: Vec<NodeId>,
+    on_stack: HashMap<NodeId, bool>,
+    components: Vec<Vec<NodeId>>,
+}
+
 // --- Implementation ---
 
 impl<T> FractalGraph<T> {
@@ -85,6 +97,134 @@ impl<T> FractalGraph<T> {
             next_node_id: 0,
         }
     }
+
+    /// Creates a new graph with the same node IDs and edges, but with each node's
+    /// payload transformed by `f`.
+    ///
+    /// Edge weights and types are carried over unchanged, so the topology of the
+    /// returned graph is identical to this one.
+    pub fn map_nodes<U, F: Fn(&T) -> U>(&self, f: F) -> FractalGraph<U> {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|(id, node)| (*id, Node { id: *id, payload: f(&node.payload) }))
+            .collect();
+
+        FractalGraph {
+            nodes,
+            edges: self.edges.clone(),
+            next_node_id: self.next_node_id,
+        }
+    }
+
+    /// Returns an iterator over the graph's nodes, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = &Node<T>> {
+        self.nodes.values()
+    }
+
+    /// Returns an iterator over the graph's node IDs, in no particular order.
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes.keys().copied()
+    }
+
+    /// Returns an iterator over every edge in the graph, paired with its source node.
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, &FractalGraphEdge)> {
+        self.edges.iter().flat_map(|(source, outgoing)| outgoing.iter().map(move |edge| (*source, edge)))
+    }
+
+    /// Builds the weighted adjacency matrix of the graph.
+    ///
+    /// Returns the node ordering, sorted by `NodeId`, together with an `N x N` matrix
+    /// whose entry `[i][j]` is the summed weight of all edges from node `i` to node `j`,
+    /// or zero if there are none.
+    pub fn to_adjacency_matrix(&self) -> (Vec<NodeId>, Vec<Vec<Complex<f32>>>) {
+        let mut ids: Vec<NodeId> = self.nodes.keys().copied().collect();
+        ids.sort_by_key(|id| id.0);
+        let index: HashMap<NodeId, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+        let mut matrix = vec![vec![Complex::new(0.0, 0.0); ids.len()]; ids.len()];
+        for (source, outgoing) in &self.edges {
+            for edge in outgoing {
+                matrix[index[source]][index[&edge.destination]] += edge.weight;
+            }
+        }
+        (ids, matrix)
+    }
+
+    /// Imports every node and edge of `other` into this graph.
+    ///
+    /// The imported nodes are assigned fresh IDs from this graph's counter (in the order
+    /// of their original IDs), so they can never collide with existing nodes. Returns a
+    /// map from each node's ID in `other` to its new ID here.
+    pub fn merge(&mut self, other: FractalGraph<T>) -> HashMap<NodeId, NodeId> {
+        let FractalGraph { nodes, mut edges, .. } = other;
+        let mut nodes: Vec<Node<T>> = nodes.into_values().collect();
+        nodes.sort_by_key(|node| node.id.0);
+
+        let mapping: HashMap<NodeId, NodeId> = nodes
+            .iter()
+            .map(|node| {
+                let id = NodeId(self.next_node_id);
+                self.next_node_id += 1;
+                (node.id, id)
+            })
+            .collect();
+
+        for node in nodes {
+            let id = mapping[&node.id];
+            let outgoing = edges
+                .remove(&node.id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|edge| FractalGraphEdge { destination: mapping[&edge.destination], ..edge })
+                .collect();
+            self.nodes.insert(id, Node { id, payload: node.payload });
+            self.edges.insert(id, outgoing);
+        }
+        mapping
+    }
+}
+
+// This block contains serialization that requires a displayable node payload.
+impl<T: Display> FractalGraph<T> {
+    /// Renders the graph in Graphviz DOT format.
+    ///
+    /// Nodes are labelled with their payload. Edges are coloured by `EdgeType`
+    /// (excitatory green, inhibitory red, resonant blue) and labelled with the magnitude
+    /// of their weight. The weight's phase sets the line style: solid when it is within
+    /// a quarter turn of zero, dashed otherwise. Nodes are written in `NodeId` order so
+    /// the output is deterministic.
+    pub fn to_dot(&self) -> String {
+        let mut ids: Vec<NodeId> = self.nodes.keys().copied().collect();
+        ids.sort_by_key(|id| id.0);
+
+        let mut dot = String::from("digraph FractalGraph {\n");
+        for id in &ids {
+            let label = self.nodes[id].payload.to_string().replace('"', "\\\"");
+            let _ = writeln!(dot, "    n{} [label=\"{}\"];", id.0, label);
+        }
+        for id in &ids {
+            for edge in self.edges.get(id).into_iter().flatten() {
+                let color = match edge.edge_type {
+                    EdgeType::Excitatory => "green",
+                    EdgeType::Inhibitory => "red",
+                    EdgeType::Resonant => "blue",
+                };
+                let style = if edge.weight.arg().abs() <= std::f32::consts::FRAC_PI_2 { "solid" } else { "dashed" };
+                let _ = writeln!(
+                    dot,
+                    "    n{} -> n{} [color={}, style={}, label=\"{:.3}\"];",
+                    id.0,
+                    edge.destination.0,
+                    color,
+                    style,
+                    edge.weight.norm()
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
 }
 
 impl<T> Default for FractalGraph<T> {
@@ -177,6 +317,11 @@ impl<T: Debug + PartialEq> FractalGraph<T> {
         self.edges.get_mut(&node_id)
     }
 
+    /// Gets the list of edges originating from a node.
+    pub fn get_edges_for_node(&self, node_id: NodeId) -> Option<&[FractalGraphEdge]> {
+        self.edges.get(&node_id).map(Vec::as_slice)
+    }
+
     /// Gets an immutable reference to a node's payload.
     pub fn get_node(&self, node_id: NodeId) -> Option<&Node<T>> {
         self.nodes.get(&node_id)
@@ -194,6 +339,144 @@ impl<T: Debug + PartialEq> FractalGraph<T> {
         self.edges.values().map(|v| v.len()).sum()
     }
 
+    /// Returns the number of edges leaving `node`, or `0` if it does not exist.
+    pub fn out_degree(&self, node: NodeId) -> usize {
+        self.edges.get(&node).map_or(0, |v| v.len())
+    }
+
+    /// Returns the number of edges arriving at `node`, or `0` if it does not exist.
+    pub fn in_degree(&self, node: NodeId) -> usize {
+        self.edges.values().flatten().filter(|e| e.destination == node).count()
+    }
+
+    /// Counts how many nodes have each total degree (in-degree plus out-degree).
+    pub fn degree_distribution(&self) -> HashMap<usize, usize> {
+        let mut degrees: HashMap<NodeId, usize> = self.edges.iter().map(|(id, out)| (*id, out.len())).collect();
+        for edge in self.edges.values().flatten() {
+            *degrees.entry(edge.destination).or_default() += 1;
+        }
+
+        let mut distribution = HashMap::new();
+        for degree in degrees.into_values() {
+            *distribution.entry(degree).or_default() += 1;
+        }
+        distribution
+    }
+
+    /// Checks whether the graph is weakly connected, i.e. connected when edge direction
+    /// is ignored. An empty graph is considered connected.
+    pub fn is_connected(&self) -> bool {
+        let Some(start) = self.nodes.keys().next() else {
+            return true;
+        };
+
+        let mut neighbours: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+        for (source, outgoing) in &self.edges {
+            for edge in outgoing {
+                neighbours.entry(*source).or_default().push(edge.destination);
+                neighbours.entry(edge.destination).or_default().push(*source);
+            }
+        }
+
+        let mut visited = HashMap::new();
+        let mut stack = vec![*start];
+        while let Some(id) = stack.pop() {
+            if visited.insert(id, true).is_some() {
+                continue;
+            }
+            stack.extend(neighbours.get(&id).into_iter().flatten().copied());
+        }
+        visited.len() == self.nodes.len()
+    }
+
+    /// Ranks nodes with PageRank, following edges with probability proportional to
+    /// `weight.norm()`.
+    ///
+    /// Each node's outgoing weight magnitudes are normalized into transition
+    /// probabilities. Dangling nodes (no outgoing edges, or only zero-weight ones)
+    /// redistribute their rank uniformly over all nodes. `damping` is the probability
+    /// of following an edge rather than teleporting (typically `0.85`). The returned
+    /// scores sum to 1; an empty graph yields an empty map.
+    pub fn pagerank(&self, damping: f64, iterations: usize) -> HashMap<NodeId, f64> {
+        let n = self.nodes.len();
+        if n == 0 {
+            return HashMap::new();
+        }
+        let uniform = 1.0 / n as f64;
+        let mut rank: HashMap<NodeId, f64> = self.nodes.keys().map(|id| (*id, uniform)).collect();
+
+        for _ in 0..iterations {
+            let mut next: HashMap<NodeId, f64> = self.nodes.keys().map(|id| (*id, 0.0)).collect();
+            let mut dangling = 0.0;
+            for (id, r) in &rank {
+                let outgoing = self.edges.get(id).map(Vec::as_slice).unwrap_or_default();
+                let total: f64 = outgoing.iter().map(|e| e.weight.norm() as f64).sum();
+                if total <= 0.0 {
+                    dangling += r;
+                    continue;
+                }
+                for edge in outgoing {
+                    *next.get_mut(&edge.destination).unwrap() += r * edge.weight.norm() as f64 / total;
+                }
+            }
+
+            let base = (1.0 - damping) * uniform + damping * dangling * uniform;
+            for r in next.values_mut() {
+                *r = base + damping * *r;
+            }
+            rank = next;
+        }
+
+        let sum: f64 = rank.values().sum();
+        rank.values_mut().for_each(|r| *r /= sum);
+        rank
+    }
+
+    /// Estimates the spectral radius (the magnitude of the dominant eigenvalue) of the
+    /// graph's weighted adjacency matrix using power iteration.
+    ///
+    /// The adjacency operator is applied implicitly from the edge weights, starting from the
+    /// all-ones vector indexed over the full node set, so disconnected graphs are handled
+    /// naturally and the estimate is deterministic. The estimate returned is the magnitude
+    /// of the Rayleigh quotient `x*Ax / x*x` of the final iterate.
+    ///
+    /// Returns `0.0` for an empty graph or one whose iterate decays to zero (e.g. a DAG).
+    pub fn spectral_radius(&self, iterations: usize) -> f32 {
+        let ids: Vec<NodeId> = self.nodes.keys().copied().collect();
+        if ids.is_empty() {
+            return 0.0;
+        }
+        let index: HashMap<NodeId, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+        // Applies the weighted adjacency operator: y_i = Σ_{i -> j} w_ij * x_j.
+        let apply = |x: &[Complex<f32>]| -> Vec<Complex<f32>> {
+            ids.iter()
+                .map(|id| {
+                    self.edges
+                        .get(id)
+                        .map(|out| out.iter().map(|e| e.weight * x[index[&e.destination]]).sum())
+                        .unwrap_or_default()
+                })
+                .collect()
+        };
+        let norm = |v: &[Complex<f32>]| v.iter().map(|c| c.norm_sqr()).sum::<f32>().sqrt();
+
+        let mut x = vec![Complex::new(1.0, 0.0); ids.len()];
+        let mut estimate = 0.0;
+        for _ in 0..iterations.max(1) {
+            let x_norm = norm(&x);
+            if x_norm < 1e-12 {
+                return 0.0;
+            }
+            x.iter_mut().for_each(|c| *c /= x_norm);
+            let y = apply(&x);
+            // With x normalized, the Rayleigh quotient reduces to x*Ax.
+            estimate = x.iter().zip(&y).map(|(a, b)| a.conj() * b).sum::<Complex<f32>>().norm();
+            x = y;
+        }
+        estimate
+    }
+
     /// Checks if the graph is a Directed Acyclic Graph (DAG).
     /// This is useful for algorithms that require no cycles, such as topological sorting.
     pub fn is_acyclic(&self) -> bool {
@@ -242,8 +525,178 @@ impl<T: Debug + PartialEq> FractalGraph<T> {
         false
     }
 
+    /// Finds every elementary cycle in the graph.
+    ///
+    /// Each cycle is returned once, as the sequence of nodes along it, starting from its
+    /// lowest `NodeId` (so rotations of the same cycle are not repeated). Parallel edges of
+    /// different types do not produce duplicate cycles, and a self-loop is a one-node cycle.
+    /// The search runs a depth-first traversal from each node in turn, tracking the current
+    /// path like `is_acyclic`'s recursion stack. The number of cycles can grow exponentially
+    /// with graph size, so this is intended for debugging modest graphs.
+    pub fn find_cycles(&self) -> Vec<Vec<NodeId>> {
+        let mut ids: Vec<NodeId> = self.nodes.keys().copied().collect();
+        ids.sort_by_key(|id| id.0);
+
+        let mut cycles = Vec::new();
+        for start in ids {
+            let mut path = vec![start];
+            let mut on_path = HashMap::from([(start, true)]);
+            self._find_cycles_util(start, start, &mut path, &mut on_path, &mut cycles);
+        }
+        cycles
+    }
+
+    /// A recursive helper for `find_cycles` that extends `path` from `node`, recording
+    /// each way back to `start` through nodes with IDs no lower than it.
+    fn _find_cycles_util(
+        &self,
+        start: NodeId,
+        node: NodeId,
+        path: &mut Vec<NodeId>,
+        on_path: &mut HashMap<NodeId, bool>,
+        cycles: &mut Vec<Vec<NodeId>>,
+    ) {
+        let mut successors: Vec<NodeId> = self
+            .edges
+            .get(&node)
+            .into_iter()
+            .flatten()
+            .map(|edge| edge.destination)
+            .filter(|next| next.0 >= start.0)
+            .collect();
+        successors.sort_by_key(|id| id.0);
+        successors.dedup();
+
+        for next in successors {
+            if next == start {
+                cycles.push(path.clone());
+            } else if !on_path.get(&next).copied().unwrap_or(false) {
+                on_path.insert(next, true);
+                path.push(next);
+                self._find_cycles_util(start, next, path, on_path, cycles);
+                path.pop();
+                on_path.insert(next, false);
+            }
+        }
+    }
+
+    /// Partitions the graph into strongly connected components using Tarjan's algorithm.
+    ///
+    /// Each inner vector is one component: a maximal set of nodes that can all reach one
+    /// another. Nodes on no cycle form singleton components. Components are returned in
+    /// reverse topological order (a component appears before any component that reaches it).
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut ids: Vec<NodeId> = self.nodes.keys().copied().collect();
+        ids.sort_by_key(|id| id.0);
+
+        let mut state = TarjanState::default();
+        for id in ids {
+            if !state.index.contains_key(&id) {
+                self._tarjan_util(id, &mut state);
+            }
+        }
+        state.components
+    }
+
+    /// A recursive helper for `strongly_connected_components` that visits `node` and closes
+    /// off a component when `node` turns out to be its root.
+    fn _tarjan_util(&self, node: NodeId, state: &mut TarjanState) {
+        let index = state.index.len();
+        state.index.insert(node, index);
+        state.low_link.insert(node, index);
+        state.stack.push(node);
+        state.on_stack.insert(node, true);
+
+        for edge in self.edges.get(&node).into_iter().flatten() {
+            let next = edge.destination;
+            if !state.index.contains_key(&next) {
+                self._tarjan_util(next, state);
+                let low = state.low_link[&node].min(state.low_link[&next]);
+                state.low_link.insert(node, low);
+            } else if state.on_stack.get(&next).copied().unwrap_or(false) {
+                let low = state.low_link[&node].min(state.index[&next]);
+                state.low_link.insert(node, low);
+            }
+        }
+
+        if state.low_link[&node] == state.index[&node] {
+            let mut component = Vec::new();
+            while let Some(member) = state.stack.pop() {
+                state.on_stack.insert(member, false);
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            state.components.push(component);
+        }
+    }
+
+    /// Computes a minimum spanning forest of the graph's undirected projection.
+    ///
+    /// Each edge is treated as undirected with cost `weight.norm()`, and Kruskal's
+    /// algorithm picks the cheapest edges that join separate trees (tracked with a
+    /// union-find). Returns `(source, destination, cost)` for every tree edge, cheapest
+    /// first. A disconnected graph yields one tree per component, so the result has
+    /// `node_count - components` edges. Self-loops are never part of the tree.
+    pub fn minimum_spanning_tree(&self) -> Vec<(NodeId, NodeId, f32)> {
+        let mut candidates: Vec<(NodeId, NodeId, f32)> = self
+            .edges
+            .iter()
+            .flat_map(|(source, outgoing)| outgoing.iter().map(move |e| (*source, e.destination, e.weight.norm())))
+            .collect();
+        // Sort by cost, with IDs as tie-breakers so the result is deterministic.
+        candidates.sort_by(|a, b| {
+            a.2.partial_cmp(&b.2)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.0.0.cmp(&b.0.0))
+                .then(a.1.0.cmp(&b.1.0))
+        });
+
+        let mut parent: HashMap<NodeId, NodeId> = self.nodes.keys().map(|id| (*id, *id)).collect();
+        fn find(parent: &mut HashMap<NodeId, NodeId>, id: NodeId) -> NodeId {
+            let root = parent[&id];
+            if root == id {
+                return id;
+            }
+            let root = find(parent, root);
+            parent.insert(id, root); // Path compression.
+            root
+        }
+
+        let mut tree = Vec::new();
+        for (source, destination, cost) in candidates {
+            let (a, b) = (find(&mut parent, source), find(&mut parent, destination));
+            if a != b {
+                parent.insert(a, b);
+                tree.push((source, destination, cost));
+            }
+        }
+        tree
+    }
+
     /// Returns an iterator that allows modifying each edge in the entire graph.
     pub fn all_edges_mut(&mut self) -> impl Iterator<Item = &mut FractalGraphEdge> {
         self.edges.values_mut().flatten()
     }
+
+    /// Transforms the weight of every edge in the graph in place.
+    ///
+    /// For example, `graph.map_edge_weights(|w| w * 0.99)` applies a global damping step.
+    pub fn map_edge_weights<F: Fn(Complex<f32>) -> Complex<f32>>(&mut self, f: F) {
+        for edge in self.all_edges_mut() {
+            edge.weight = f(edge.weight);
+        }
+    }
+
+    /// Returns a copy of the graph with every edge weight transformed by `f`,
+    /// leaving this graph untouched.
+    pub fn with_edge_weights<F: Fn(Complex<f32>) -> Complex<f32>>(&self, f: F) -> Self
+    where
+        T: Clone,
+    {
+        let mut graph = self.clone();
+        graph.map_edge_weights(f);
+        graph
+    }
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 //! Defines `GraphEdge`, a struct representing a directed edge in a geometric space.
 
+use crate::constants::MODULUS;
+use crate::fractaledge::FractalEdge;
 use crate::vec3::Vec3;
 use num_complex::Complex;
 
@@ -22,6 +24,20 @@ pub struct GraphEdge {
     pub data: Complex<f32>,
 }
 
+/// Ceilings on the length and amplitude an edge can reach through `GraphEdge::with_entropy`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EntropyBounds {
+    pub max_length: f32,
+    pub max_amplitude: f32,
+}
+
+impl Default for EntropyBounds {
+    /// Generous ceilings of `1e6` that only bite on runaway growth.
+    fn default() -> Self {
+        EntropyBounds { max_length: 1e6, max_amplitude: 1e6 }
+    }
+}
+
 impl GraphEdge {
     /// Computes the spatial endpoint of the edge.
     /// `endpoint = origin + direction * length`
@@ -49,11 +65,69 @@ impl GraphEdge {
         }
     }
 
+    /// Checks whether two edges share the same geometry and have complex data within
+    /// `eps` of each other.
+    ///
+    /// Origin, direction, length and depth are compared exactly, so this suits data-only
+    /// operations; use `FractalField::approx_eq` when the geometry is also perturbed.
+    pub fn approx_eq(&self, other: &GraphEdge, eps: f32) -> bool {
+        self.origin == other.origin
+            && self.direction == other.direction
+            && self.length == other.length
+            && self.depth == other.depth
+            && (self.data - other.data).norm() <= eps
+    }
+
     /// Modulates the edge's properties based on an entropy value.
     /// This can be used to simulate noise, decay, or other environmental interactions.
+    ///
+    /// This used to scale without limit; growth is now clamped to the default
+    /// `EntropyBounds`, see `with_entropy_bounded`. An edge that already exceeds a ceiling
+    /// keeps its value but cannot grow any further.
     pub fn apply_entropy(&mut self, entropy: f32) {
-        self.length *= 1.0 + entropy;
-        self.data *= Complex::new(1.0 + entropy, entropy);
+        *self = self.with_entropy(entropy);
+    }
+
+    /// Returns a copy of the edge modulated by `entropy`, clamped to the default `EntropyBounds`.
+    pub fn with_entropy(&self, entropy: f32) -> GraphEdge {
+        self.with_entropy_bounded(entropy, EntropyBounds::default())
+    }
+
+    /// Returns a copy of the edge modulated by `entropy`, with the growth of its length and
+    /// amplitude clamped to the given ceilings.
+    ///
+    /// The length is scaled by `1 + entropy` and the data multiplied by
+    /// `(1 + entropy) + i·entropy`. The product is formed in polar form, so repeated
+    /// application saturates at the ceilings instead of overflowing to infinity.
+    ///
+    /// Only the growth from this call is clamped: a magnitude already above its ceiling is
+    /// kept, not pulled down. The length is clamped by magnitude, so an `entropy` below
+    /// `-1`, which flips the length's sign, cannot grow it without bound either.
+    pub fn with_entropy_bounded(&self, entropy: f32, bounds: EntropyBounds) -> GraphEdge {
+        let (amp, phase) = self.data.to_polar();
+        let (gain, shift) = Complex::new(1.0 + entropy, entropy).to_polar();
+        GraphEdge {
+            length: clamp_growth(self.length, self.length * (1.0 + entropy), bounds.max_length),
+            data: Complex::from_polar(clamp_growth(amp, amp * gain, bounds.max_amplitude), phase + shift),
+            ..*self
+        }
+    }
+
+    /// Builds a geometric edge from an abstract `FractalEdge`, placed at `origin` and
+    /// pointing along `direction`.
+    ///
+    /// The edge's `data` is the `FractalEdge`'s complex amplitude, which already encodes its
+    /// phase, and its `depth` is the `location`. A `location` too large for a `u32` depth
+    /// saturates at `u32::MAX`. Since a `FractalEdge` has no extent, the length is set to
+    /// `1.0`.
+    pub fn from_fractal_edge(edge: &FractalEdge, origin: Vec3, direction: Vec3) -> GraphEdge {
+        GraphEdge {
+            origin,
+            direction,
+            length: 1.0,
+            depth: u32::try_from(edge.location).unwrap_or(u32::MAX),
+            data: edge.amplitude,
+        }
     }
 
     /// Computes a crude similarity score between two edges.
@@ -66,4 +140,26 @@ impl GraphEdge {
         // A simple linear combination for a similarity metric.
         dir_dot - len_diff - data_diff
     }
-}
\ No newline at end of file
+}
+
+/// Clamps the magnitude of `new` to `ceiling`, or to the magnitude of `old` if that is
+/// already larger, so that existing values are never reduced.
+fn clamp_growth(old: f32, new: f32, ceiling: f32) -> f32 {
+    let limit = ceiling.max(old.abs());
+    new.clamp(-limit, limit)
+}
+
+/// Converts a geometric edge into the abstract representation used by the resonance machinery.
+///
+/// The complex `data` becomes the `amplitude`, and `phase` is its argument. The `location`
+/// is the edge's `depth` wrapped by `MODULUS`, so edges on the same recursion level share a
+/// location. The geometry (origin, direction, length) has no abstract counterpart and is dropped.
+impl From<&GraphEdge> for FractalEdge {
+    fn from(edge: &GraphEdge) -> Self {
+        FractalEdge {
+            amplitude: edge.data,
+            location: edge.depth as usize % MODULUS,
+            phase: edge.data.arg(),
+        }
+    }
+}
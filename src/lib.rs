@@ -30,7 +30,7 @@
 //!
 //! ```no_run
 //! use fractal_algebra::{
-//!     GeneratorCriticLoop, EvolutionaryGenerator, MutationSuite, CriticSuite,
+//!     GeneratorCriticLoopBuilder, EvolutionaryGenerator, MutationSuite, CriticSuite,
 //!     SymmetryCritic, EntropyCritic, FractalField
 //! };
 //!
@@ -38,6 +38,9 @@
 //! let generator = EvolutionaryGenerator {
 //!     mutations: MutationSuite::new(), // Add strategies here
 //!     count: 10,
+//!     crossover_rate: 0.0,
+//!     secondary_parent: None,
+//!     seed_count: 1,
 //! };
 //!
 //! // 2. Set up critics to evaluate the fields.
@@ -46,11 +49,12 @@
 //! critics.add_critic(EntropyCritic, 0.3);
 //!
 //! // 3. Create and run the evolutionary loop.
-//! let loop_engine = GeneratorCriticLoop {
-//!     generator,
-//!     critic_suite: critics,
-//!     iterations: 100,
-//! };
+//! let loop_engine = GeneratorCriticLoopBuilder::new()
+//!     .generator(generator)
+//!     .critics(critics)
+//!     .iterations(100)
+//!     .build()
+//!     .expect("a generator was set");
 //!
 //! if let Some(best_field) = loop_engine.run() {
 //!     println!("Found a best field!");
@@ -59,19 +63,28 @@
 //! ```
 
 // --- Module Declarations ---
+pub mod annealing;
 pub mod atom;
+pub mod bench;
+pub mod cluster;
 pub mod constants;
 pub mod criticloop;
 pub mod critics;
+pub mod crossover;
+pub mod depth;
+pub mod diversity;
 pub mod evolutionary;
 pub mod field;
 pub mod filters;
 pub mod fractaledge;
+pub mod geometric;
 pub mod graph;
 pub mod graphedge;
 pub mod laws;
 pub mod looprep;
 pub mod mutation;
+pub mod novelty;
+pub mod registry;
 pub mod resonance;
 pub mod rfg;
 pub mod signature;
@@ -81,6 +94,7 @@ pub mod tests;
 pub mod time;
 pub mod traits;
 pub mod vec3;
+pub mod zmod;
 pub mod ai;
 pub mod bayes;
 
@@ -94,38 +108,54 @@ mod macros;
 pub use constants::MODULUS;
 pub use field::FractalField;
 pub use fractaledge::FractalEdge;
-pub use graphedge::GraphEdge;
-pub use signature::FractalSignature;
+pub use graphedge::{EntropyBounds, GraphEdge};
+pub use signature::{FractalSignature, SignatureFeature, SignatureHistogram};
 pub use vec3::Vec3;
+pub use zmod::Zmod;
 
 // Graph-related types
 pub use graph::{FractalGraph, FractalGraphEdge, EdgeType, GraphError, NodeId};
 
 // Evolutionary loop components
-pub use criticloop::GeneratorCriticLoop;
+pub use bench::{benchmark_loop, BenchStats};
+pub use criticloop::{GeneratorCriticLoop, GeneratorCriticLoopBuilder};
 pub use critics::CriticSuite;
-pub use evolutionary::EvolutionaryGenerator;
-pub use looprep::LoopReport;
+pub use crossover::Crossover;
+pub use diversity::population_diversity;
+pub use evolutionary::{EvolutionaryGenerator, GenericEvolutionaryGenerator};
+pub use looprep::{FullLoopReport, LoopReport, ScoreStats};
 pub use mutation::MutationSuite;
+pub use novelty::NoveltySearch;
+pub use registry::{GeneratorFactory, GeneratorRegistry};
 pub use rfg::RandomFieldGenerator;
 
 // AI and Quantum-Inspired components
-pub use ai::{EntangledSystem, EntropyPulse, FeedbackSignal, ParticleResonance, ProbabilisticSearch};
-pub use bayes::{FrequencyBeliefSpace, Gaussian};
+pub use ai::{
+    BandConstraint, EntangledSystem, EntropyPulse, FeedbackSignal, ParticleResonance,
+    ProbabilisticSearch, SymmetryConstraint,
+};
+pub use annealing::SimulatedAnnealing;
+pub use bayes::{BeliefSpace, FrequencyBeliefSpace, Gaussian};
+pub use cluster::cluster_fields;
 pub use atom::{FractalAtom};
 
 // Resonance and Transformation framework
 pub use resonance::{
-    Resonance, ResonanceFilter, ResonanceLaw, ResonantTransform,
-    TransformResonanceLaw,
+    aggregate_resonance, dominant_law, NullResonance, Resonance, ResonanceFilter,
+    ResonanceLaw, ResonantTransform, TransformResonanceLaw,
+};
+pub use filters::{
+    AnyTagFilter, DescriptionFilter, EntropyRangeFilter, FieldFilter, FilterTrace,
+    FractalPredicate, FractalPredicateFilter, LawFieldFilter, LawFilter, PredicateFilter,
+    ScoreFieldFilter, ScoreFilter, TagPrefixFilter,
 };
-pub use filters::{FilterTrace, LawFilter, PredicateFilter, ScoreFilter};
 
 // Core Traits
 pub use traits::{
-    CollectionMember, Critic, EntropyCritic, Fractal, FractalClone,
-    FractalCollection, Generator, HasSignature, IFS, Mandelbrot, MutationStrategy,
-    Operation, SymmetryCritic,
+    count_nodes, fold_fractal, AffineIFS, CoherenceCritic, CollectionMember, Critic,
+    EntropyCritic, FieldClass, Fractal, FractalClone, FractalCollection, Generator, HasSignature,
+    IFS, Julia, Mandelbrot, MutationStrategy, Operation, SimplicityCritic, SymmetryCritic,
+    TransformDeltaCritic,
 };
 
 // Spacetime simulation types
@@ -14,4 +14,73 @@ pub struct LoopReport {
     pub best_score: f32,
     /// A history of the best candidate from each iteration, along with its score.
     pub history: Vec<(FractalField, f32)>,
-}
\ No newline at end of file
+    /// The `population_diversity` of each iteration's candidates, aligned with `history`.
+    /// A steady decline towards zero signals that the population is collapsing.
+    pub diversity: Vec<f32>,
+    /// Running statistics of the scores recorded in `history`.
+    pub score_stats: ScoreStats,
+}
+
+/// Streaming statistics of a sequence of scores, updated in constant memory.
+///
+/// The mean and variance are maintained with Welford's algorithm, which stays numerically
+/// stable over long runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreStats {
+    pub count: usize,
+    pub mean: f32,
+    /// The sum of squared deviations from the mean (Welford's `M2`).
+    m2: f32,
+    /// The smallest score seen, or `+∞` before any score is pushed.
+    pub min: f32,
+    /// The largest score seen, or `-∞` before any score is pushed.
+    pub max: f32,
+}
+
+impl ScoreStats {
+    /// Creates an empty `ScoreStats`.
+    pub fn new() -> Self {
+        ScoreStats { count: 0, mean: 0.0, m2: 0.0, min: f32::INFINITY, max: f32::NEG_INFINITY }
+    }
+
+    /// Records a score.
+    pub fn push(&mut self, score: f32) {
+        self.count += 1;
+        let delta = score - self.mean;
+        self.mean += delta / self.count as f32;
+        self.m2 += delta * (score - self.mean);
+        self.min = self.min.min(score);
+        self.max = self.max.max(score);
+    }
+
+    /// Returns the population variance of the scores, or `0.0` if none were pushed.
+    pub fn variance(&self) -> f32 {
+        if self.count == 0 { 0.0 } else { self.m2 / self.count as f32 }
+    }
+
+    /// Returns `(count, mean, variance, min, max)`.
+    pub fn summary(&self) -> (f32, f32, f32, f32, f32) {
+        (self.count as f32, self.mean, self.variance(), self.min, self.max)
+    }
+}
+
+impl Default for ScoreStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An extended report that records every candidate of every generation.
+///
+/// This struct is returned by `run_with_full_report`. Since it keeps the whole population
+/// of each generation, it is considerably more memory-intensive than `LoopReport` and is
+/// intended for analyzing selection pressure rather than routine runs.
+#[derive(Debug)]
+pub struct FullLoopReport {
+    /// The best `FractalField` found during the entire run.
+    pub best_field: FractalField,
+    /// The score of the `best_field`.
+    pub best_score: f32,
+    /// All candidates and their scores, one inner vector per non-empty generation.
+    pub generations: Vec<Vec<(FractalField, f32)>>,
+}
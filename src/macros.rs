@@ -8,6 +8,9 @@
 /// - Multiplicative identity (f * 1 = f)
 /// - Multiplication by zero (f * 0 = 0)
 ///
+/// Results that should be zero keep the edges of `f`, so they are checked with
+/// `FractalField::is_zero` rather than compared to the empty `FractalField::zero()`.
+///
 /// # Usage
 ///
 /// ```
@@ -20,6 +23,7 @@
 ///     test_vector_space_axioms!(my_field, my_scalar);
 /// }
 /// ```
+#[allow(clippy::test_attr_in_doctest)]
 #[macro_export]
 macro_rules! test_vector_space_axioms {
     ($field:expr, $scalar:expr) => {{
@@ -34,11 +38,11 @@ macro_rules! test_vector_space_axioms {
         assert_eq!(f.clone() + zero.clone(), f);
         assert_eq!(zero.clone() + f.clone(), f);
         // Test additive inverse
-        assert_eq!(f.clone() + (-f.clone()), zero);
+        assert!((f.clone() + (-f.clone())).is_zero());
         // Test multiplicative identity
         assert_eq!(f.clone() * Complex::new(1.0, 0.0), f);
         // Test multiplication by zero scalar
-        assert_eq!(f.clone() * Complex::new(0.0, 0.0), zero);
+        assert!((f.clone() * Complex::new(0.0, 0.0)).is_zero());
         // A simple distributivity check (Note: this is just one case)
         assert_eq!(f.clone() * s + f.clone() * s, f.clone() * (s + s));
     }};
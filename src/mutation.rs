@@ -65,4 +65,16 @@ impl MutationSuite {
         // but it's good practice for robustness.
         field.clone()
     }
-}
\ No newline at end of file
+}
+
+/// A mutation strategy that returns the field unchanged.
+///
+/// Registering it in a `MutationSuite` gives elitism: its weight controls how often
+/// the parent is carried forward untouched.
+pub struct Identity;
+
+impl MutationStrategy for Identity {
+    fn mutate(&self, field: &FractalField) -> FractalField {
+        field.clone()
+    }
+}
@@ -0,0 +1,75 @@
+//! Implements novelty search, an open-ended alternative to critic-based selection.
+//!
+//! Rather than climbing a critic's score, `NoveltySearch` rewards candidates for being
+//! different from everything it has already seen. Each generation's most novel field is
+//! recorded in an archive of signatures, which in turn pushes later generations elsewhere.
+
+use crate::field::FractalField;
+use crate::signature::FractalSignature;
+use crate::traits::Generator;
+use std::cmp::Ordering;
+
+/// An evolutionary loop that selects for novelty relative to an archive of past signatures.
+pub struct NoveltySearch<G: Generator> {
+    /// The generator responsible for creating initial and mutated `FractalField`s.
+    pub generator: G,
+    /// The number of nearest archive neighbours averaged to measure novelty.
+    pub k: usize,
+    /// The total number of generations to run.
+    pub iterations: usize,
+    /// The signatures of every field selected so far, oldest first.
+    pub archive: Vec<FractalSignature>,
+}
+
+impl<G: Generator> NoveltySearch<G> {
+    /// Creates a new `NoveltySearch` with an empty archive.
+    pub fn new(generator: G, k: usize, iterations: usize) -> Self {
+        NoveltySearch { generator, k, iterations, archive: Vec::new() }
+    }
+
+    /// Scores how novel a signature is: the mean `distance` to its `k` nearest neighbours
+    /// in the archive (or to the whole archive, if it is smaller). An empty archive makes
+    /// everything equally novel, scoring `0.0`.
+    pub fn novelty(&self, signature: &FractalSignature) -> f32 {
+        let mut distances: Vec<f32> = self.archive.iter().map(|a| signature.distance(a)).collect();
+        if distances.is_empty() {
+            return 0.0;
+        }
+        distances.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let nearest = &distances[..self.k.clamp(1, distances.len())];
+        nearest.iter().sum::<f32>() / nearest.len() as f32
+    }
+
+    /// Runs the search and returns the field selected in each generation.
+    ///
+    /// Each generation mutates the previously selected field (or asks the generator for its
+    /// initial population), picks the candidate with the highest `novelty`, and adds its
+    /// signature to the archive. Generations in which the generator produced no candidates
+    /// are skipped. The archive persists, so calling `run` again continues the search.
+    pub fn run(&mut self) -> Vec<FractalField> {
+        let mut selected: Vec<FractalField> = Vec::new();
+
+        for _ in 0..self.iterations {
+            let candidates = match selected.last() {
+                Some(f) => self.generator.mutate(f),
+                None => self.generator.generate(),
+            };
+
+            let most_novel = candidates
+                .into_iter()
+                .map(|c| {
+                    let signature = c.signature();
+                    let novelty = self.novelty(&signature);
+                    (c, signature, novelty)
+                })
+                .max_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal));
+
+            if let Some((field, signature, _)) = most_novel {
+                self.archive.push(signature);
+                selected.push(field);
+            }
+        }
+
+        selected
+    }
+}
@@ -0,0 +1,72 @@
+//! Defines `GeneratorRegistry`, a lookup table of generators by name.
+//!
+//! This allows experiments to be configured from plain strings (e.g. read from a config
+//! file) rather than from concrete generator types chosen at compile time.
+
+use crate::evolutionary::EvolutionaryGenerator;
+use crate::mutation::MutationSuite;
+use crate::rfg::RandomFieldGenerator;
+use crate::stochastic::StochasticAmplitudePhase;
+use crate::traits::Generator;
+use std::collections::HashMap;
+
+/// A factory producing a fresh, boxed `Generator`.
+pub type GeneratorFactory = Box<dyn Fn() -> Box<dyn Generator>>;
+
+/// Maps names to factories that build `Generator` trait objects.
+#[derive(Default)]
+pub struct GeneratorRegistry {
+    factories: HashMap<String, GeneratorFactory>,
+}
+
+impl GeneratorRegistry {
+    /// Creates a new, empty registry.
+    pub fn new() -> Self {
+        GeneratorRegistry { factories: HashMap::new() }
+    }
+
+    /// Creates a registry with the library's built-in generators:
+    ///
+    /// - `"random_field"`: a `RandomFieldGenerator` producing 10 candidates with a mutation
+    ///   strength of 0.5.
+    /// - `"evolutionary"`: an `EvolutionaryGenerator` producing 10 candidates by jittering
+    ///   amplitude and phase by up to 0.1.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register("random_field", || {
+            Box::new(RandomFieldGenerator { count: 10, mutation_strength: 0.5 })
+        });
+        registry.register("evolutionary", || {
+            let mut mutations = MutationSuite::new();
+            mutations.add_strategy(StochasticAmplitudePhase { amplitude_jitter: 0.1, phase_jitter: 0.1 }, 1.0);
+            Box::new(EvolutionaryGenerator {
+                mutations,
+                count: 10,
+                crossover_rate: 0.0,
+                secondary_parent: None,
+                seed_count: 1,
+            })
+        });
+        registry
+    }
+
+    /// Registers `factory` under `name`, replacing any factory already registered there.
+    pub fn register<F>(&mut self, name: &str, factory: F)
+    where
+        F: Fn() -> Box<dyn Generator> + 'static,
+    {
+        self.factories.insert(name.to_string(), Box::new(factory));
+    }
+
+    /// Builds a new generator from the factory registered under `name`, if any.
+    pub fn create(&self, name: &str) -> Option<Box<dyn Generator>> {
+        self.factories.get(name).map(|factory| factory())
+    }
+
+    /// Returns the registered names, sorted alphabetically.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.factories.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+}
@@ -5,6 +5,7 @@
 //! the `Resonance` trait, implementations for various types, and tools for transforming
 //! and filtering objects based on their resonant properties.
 
+use crate::field::FractalField;
 use crate::fractaledge::FractalEdge;
 use crate::signature::FractalSignature;
 use crate::traits::{Fractal, FractalQuantumSpace};
@@ -92,9 +93,68 @@ impl std::fmt::Display for ResonanceLaw {
     }
 }
 
-/// A blanket implementation of `Resonance` for any type that has a `Default` and is `'static`.
-/// This provides a non-resonant baseline behavior for any type that hasn't defined its own.
-impl<T: Default + 'static> Resonance for T {
+/// Classifies a single amplitude/phase pair into a `ResonanceLaw`.
+///
+/// These are the thresholds used for `FractalEdge`: negligible amplitudes are `Null`,
+/// phases near 0 are `Harmony` and near π are `Dissonance`, and large amplitudes are an
+/// `EntropyPulse`. Anything else is an `Echo`.
+///
+/// The phase is first wrapped into `(-π, π]`, so closeness is measured as an angle:
+/// `-π` and `π` are both `Dissonance`, and a phase just below `2π` is `Harmony`.
+pub fn classify_phasor(amplitude: f32, phase: f32) -> ResonanceLaw {
+    let phase = PI - (PI - phase).rem_euclid(2.0 * PI);
+
+    if amplitude < 0.01 { ResonanceLaw::Null }
+    else if phase.abs() < 0.1 { ResonanceLaw::Harmony }
+    else if PI - phase.abs() < 0.1 { ResonanceLaw::Dissonance }
+    else if amplitude > 10.0 { ResonanceLaw::EntropyPulse }
+    else { ResonanceLaw::Echo }
+}
+
+/// Computes the mean resonance score across a collection of resonant objects.
+///
+/// Returns `0.0` for an empty collection.
+pub fn aggregate_resonance(items: &[&dyn Resonance]) -> f64 {
+    if items.is_empty() {
+        return 0.0;
+    }
+    items.iter().map(|r| r.resonance_score()).sum::<f64>() / items.len() as f64
+}
+
+/// Returns the most frequent `ResonanceLaw` among a collection of resonant objects.
+///
+/// Ties are broken in favour of the law that appears first. An empty collection
+/// yields `ResonanceLaw::Null`.
+pub fn dominant_law(items: &[&dyn Resonance]) -> ResonanceLaw {
+    // A Vec preserves first-appearance order, which makes tie-breaking deterministic.
+    let mut counts: Vec<(ResonanceLaw, usize)> = Vec::new();
+    for item in items {
+        let law = item.resonance_law();
+        match counts.iter_mut().find(|(l, _)| *l == law) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((law, 1)),
+        }
+    }
+
+    let mut dominant = (ResonanceLaw::Null, 0);
+    for (law, count) in counts {
+        if count > dominant.1 {
+            dominant = (law, count);
+        }
+    }
+    dominant.0
+}
+
+/// A wrapper that gives any value a non-resonant baseline behavior.
+///
+/// This replaces the former blanket `impl<T: Default> Resonance for T`, which silently
+/// claimed every `Default` type and prevented such types from defining their own
+/// resonance. Types that relied on that baseline should now be wrapped explicitly,
+/// e.g. `NullResonance(value)`, or implement `Resonance` directly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NullResonance<T>(pub T);
+
+impl<T: 'static> Resonance for NullResonance<T> {
     fn as_any(&self) -> &dyn Any { self }
     fn resonance_score(&self) -> f64 { 0.0 }
     fn resonance_similarity(&self, _other: &dyn Resonance) -> f64 { 0.0 }
@@ -137,14 +197,7 @@ impl Resonance for FractalEdge {
     }
 
     fn resonance_law(&self) -> ResonanceLaw {
-        let amp = self.amplitude.norm();
-        let phase = self.phase % (2.0 * PI);
-
-        if amp < 0.01 { ResonanceLaw::Null }
-        else if phase.abs() < 0.1 { ResonanceLaw::Harmony }
-        else if (phase - PI).abs() < 0.1 { ResonanceLaw::Dissonance }
-        else if amp > 10.0 { ResonanceLaw::EntropyPulse }
-        else { ResonanceLaw::Echo }
+        classify_phasor(self.amplitude.norm(), self.phase)
     }
 
     fn resonance_signature(&self) -> Option<Vec<f64>> {
@@ -157,6 +210,42 @@ impl Resonance for FractalEdge {
     }
 }
 
+/// An implementation of `Resonance` for `FractalField`, built on its edges and signature.
+impl Resonance for FractalField {
+    fn as_any(&self) -> &dyn Any { self }
+
+    /// The sum of the resonance scores of the field's edges, each viewed as a `FractalEdge`.
+    fn resonance_score(&self) -> f64 {
+        self.edges.iter().map(|e| FractalEdge::from(e).resonance_score()).sum()
+    }
+
+    /// The cosine similarity of the two fields' signature feature vectors, clamped to `[0, 1]`.
+    fn resonance_similarity(&self, other: &dyn Resonance) -> f64 {
+        let Some(other_field) = other.as_any().downcast_ref::<FractalField>() else {
+            return 0.0;
+        };
+        let a = self.signature().features();
+        let b = other_field.signature().features();
+
+        let dot: f32 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+        let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm_a == 0.0 || norm_b == 0.0 {
+            // Two empty fields are trivially alike; an empty and a non-empty field are not.
+            return if norm_a == norm_b { 1.0 } else { 0.0 };
+        }
+        (dot / (norm_a * norm_b)).clamp(0.0, 1.0) as f64
+    }
+
+    fn resonance_law(&self) -> ResonanceLaw {
+        self.dominant_law()
+    }
+
+    fn resonance_signature(&self) -> Option<Vec<f64>> {
+        Some(self.signature().features().iter().map(|x| *x as f64).collect())
+    }
+}
+
 /// A trait for transformations that can affect an object's resonance.
 pub trait ResonantTransform<T: Resonance> {
     /// Applies the transformation to a resonant object, returning the new state.
@@ -198,6 +287,56 @@ impl ResonantTransform<FractalEdge> for PhaseShift {
     }
 }
 
+/// A transformation that scales the amplitude of a `FractalEdge`.
+///
+/// Since an edge's resonance score is proportional to its amplitude, a `factor`
+/// above 1 is `Amplifying` and a `factor` below 1 is `Dampening` for any edge
+/// with a non-zero score.
+pub struct AmplitudeScale {
+    pub factor: f32,
+}
+
+impl ResonantTransform<FractalEdge> for AmplitudeScale {
+    fn apply(&self, input: &FractalEdge) -> FractalEdge {
+        FractalEdge { amplitude: input.amplitude * self.factor, ..*input }
+    }
+}
+
+/// A pipeline of transformations applied in sequence.
+///
+/// Because `resonance_delta` and `transform_law` are derived from `apply`, they
+/// reflect the effect of the whole chain rather than any single stage.
+pub struct ComposedTransform<T: Resonance> {
+    pub transforms: Vec<Box<dyn ResonantTransform<T>>>,
+}
+
+impl<T: Resonance> ComposedTransform<T> {
+    /// Creates a new, empty pipeline (the identity transform).
+    pub fn new() -> Self {
+        ComposedTransform { transforms: Vec::new() }
+    }
+
+    /// Appends a transform to the end of the pipeline.
+    pub fn then<R: ResonantTransform<T> + 'static>(mut self, transform: R) -> Self {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+}
+
+impl<T: Resonance> Default for ComposedTransform<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Resonance + Clone> ResonantTransform<T> for ComposedTransform<T> {
+    fn apply(&self, input: &T) -> T {
+        self.transforms
+            .iter()
+            .fold(input.clone(), |acc, transform| transform.apply(&acc))
+    }
+}
+
 /// A filter that selects objects based on resonance criteria.
 pub trait ResonanceFilter {
     fn apply(&self, units: &[SemanticUnit]) -> Vec<SemanticUnit>;
@@ -31,24 +31,32 @@ pub struct RandomFieldGenerator {
     pub mutation_strength: f32,
 }
 
+impl RandomFieldGenerator {
+    /// Returns an endless iterator of random fields, produced lazily one at a time.
+    ///
+    /// Use `take` to draw as many as needed without allocating a whole population;
+    /// `generate` is `generate_iter().take(count)` collected.
+    pub fn generate_iter(&self) -> impl Iterator<Item = FractalField> + '_ {
+        let mut rng = rand::rng();
+        std::iter::repeat_with(move || {
+            let edges = (0..5) // Generate 5 random edges per field
+                .map(|_| GraphEdge {
+                    origin: Vec3::random(),
+                    direction: Vec3::X,
+                    length: 1.0,
+                    depth: rng.random_range(0..5),
+                    data: Complex::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0)),
+                })
+                .collect();
+            FractalField { edges }
+        })
+    }
+}
+
 impl Generator for RandomFieldGenerator {
     /// Produces an initial population of `FractalField`s, each with random edges.
     fn generate(&self) -> Vec<FractalField> {
-        let mut rng = rand::rng();
-        (0..self.count)
-            .map(|_| {
-                let edges = (0..5) // Generate 5 random edges per field
-                    .map(|_| GraphEdge {
-                        origin: Vec3::random(),
-                        direction: Vec3::X,
-                        length: 1.0,
-                        depth: rng.random_range(0..5),
-                        data: Complex::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0)),
-                    })
-                    .collect();
-                FractalField { edges }
-            })
-            .collect()
+        self.generate_iter().take(self.count).collect()
     }
 
     /// Mutates a given field by creating `count` new variations, each with stochastically
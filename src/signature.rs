@@ -1,5 +1,6 @@
 //! Defines `FractalSignature`, a condensed summary of a `FractalField`'s properties.
 
+use crate::field::FractalField;
 use crate::resonance::SemanticUnit;
 
 /// A struct that holds aggregated data about a `FractalField`,
@@ -15,11 +16,34 @@ pub struct FractalSignature {
 
 impl FractalSignature {
     /// Calculates a simple distance metric between two signatures.
+    ///
+    /// This is the L1 (Manhattan) distance between their `features()` vectors.
     pub fn distance(&self, other: &Self) -> f32 {
-        (self.total_amplitude - other.total_amplitude).abs()
-        + (self.average_phase - other.average_phase).abs()
-        + (self.entropy - other.entropy).abs()
-        + (self.edge_count as i32 - other.edge_count as i32).abs() as f32
+        feature_distance(&self.features(), &other.features())
+    }
+
+    /// Returns the signature's numeric features as a vector:
+    /// `[total_amplitude, average_phase, entropy, edge_count]`.
+    pub fn features(&self) -> [f32; 4] {
+        [self.total_amplitude, self.average_phase, self.entropy, self.edge_count as f32]
+    }
+
+    /// Returns a hashable key that buckets nearby signatures together.
+    ///
+    /// The float fields (`total_amplitude`, `average_phase`, `entropy`) are rounded to the
+    /// nearest multiple of `resolution`, and the integer fields are kept as they are. Two
+    /// signatures whose float fields lie in the same grid cell share a key; note that
+    /// arbitrarily close signatures can still fall either side of a cell boundary.
+    pub fn quantized_key(&self, resolution: f32) -> (i64, i64, i64, usize, u32, u32) {
+        let quantize = |x: f32| (x / resolution).round() as i64;
+        (
+            quantize(self.total_amplitude),
+            quantize(self.average_phase),
+            quantize(self.entropy),
+            self.edge_count,
+            self.depth_range.0,
+            self.depth_range.1,
+        )
     }
 
     /// Checks if the signature is symmetric, defined as having an average phase
@@ -61,4 +85,92 @@ impl FractalSignature {
             depth_range: (min_depth, max_depth),
         }
     }
-}
\ No newline at end of file
+}
+
+/// Computes the L1 distance between two signature feature vectors.
+/// This is the metric behind `FractalSignature::distance`.
+pub fn feature_distance(a: &[f32; 4], b: &[f32; 4]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum()
+}
+
+/// The scalar property of a `FractalSignature` used to bin fields in a `SignatureHistogram`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureFeature {
+    Entropy,
+    TotalAmplitude,
+}
+
+impl SignatureFeature {
+    /// Extracts this feature's value from a signature.
+    pub fn value(&self, signature: &FractalSignature) -> f32 {
+        match self {
+            SignatureFeature::Entropy => signature.entropy,
+            SignatureFeature::TotalAmplitude => signature.total_amplitude,
+        }
+    }
+}
+
+/// A histogram of a population of fields, binned by one feature of their signatures.
+///
+/// Bins are equal-width and span the observed range of the feature, so a collapsed
+/// population (all fields alike) shows up as a single populated bin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureHistogram {
+    /// The signature feature the fields were binned by.
+    pub feature: SignatureFeature,
+    /// The smallest observed feature value (the lower edge of the first bin).
+    pub min: f32,
+    /// The largest observed feature value (the upper edge of the last bin).
+    pub max: f32,
+    /// The number of fields falling into each bin.
+    pub counts: Vec<usize>,
+}
+
+impl SignatureHistogram {
+    /// Bins fields by the `entropy` of their signatures.
+    pub fn from_fields(fields: &[FractalField], bins: usize) -> Self {
+        Self::from_fields_by(fields, bins, SignatureFeature::Entropy)
+    }
+
+    /// Bins fields by the chosen feature of their signatures.
+    ///
+    /// At least one bin is always created, even if `bins` is zero.
+    pub fn from_fields_by(fields: &[FractalField], bins: usize, feature: SignatureFeature) -> Self {
+        let bins = bins.max(1);
+        let values: Vec<f32> = fields.iter().map(|f| feature.value(&f.signature())).collect();
+
+        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let mut counts = vec![0; bins];
+
+        if values.is_empty() {
+            return SignatureHistogram { feature, min: 0.0, max: 0.0, counts };
+        }
+
+        let width = (max - min) / bins as f32;
+        for value in values {
+            let bin = if width > 0.0 { ((value - min) / width) as usize } else { 0 };
+            // The maximum value lands exactly on the upper edge; keep it in the last bin.
+            counts[bin.min(bins - 1)] += 1;
+        }
+
+        SignatureHistogram { feature, min, max, counts }
+    }
+
+    /// Returns the index of the most populated bin, preferring the lowest index on ties.
+    pub fn mode(&self) -> usize {
+        let mut mode = 0;
+        for (i, count) in self.counts.iter().enumerate() {
+            if *count > self.counts[mode] {
+                mode = i;
+            }
+        }
+        mode
+    }
+
+    /// Returns the `(lower, upper)` feature range covered by a bin.
+    pub fn bin_range(&self, bin: usize) -> (f32, f32) {
+        let width = (self.max - self.min) / self.counts.len() as f32;
+        (self.min + width * bin as f32, self.min + width * (bin + 1) as f32)
+    }
+}
@@ -39,8 +39,60 @@ pub fn test_add_inverse() -> bool {
 
 // --- Unit Tests for other modules ---
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
-    use crate::atom::{TagSet, TagSetError};
+    use crate::ai::{
+        BandConstraint, EntangledSystem, EntropyPulse, FeedbackSignal, ParticleResonance,
+        ProbabilisticSearch, SymmetryConstraint,
+    };
+    use crate::annealing::SimulatedAnnealing;
+    use crate::atom::{FractalAtom, Metadata, TagSet, TagSetError};
+    use crate::bayes::{BeliefSpace, FrequencyBeliefSpace, Gaussian};
+    use crate::bench::benchmark_loop;
+    use crate::cluster::cluster_fields;
+    use crate::constants::MODULUS;
+    use crate::criticloop::{GeneratorCriticLoop, GeneratorCriticLoopBuilder};
+    use crate::critics::CriticSuite;
+    use crate::crossover::Crossover;
+    use crate::depth::DepthMutation;
+    use crate::diversity::population_diversity;
+    use crate::evolutionary::{EvolutionaryGenerator, GenericEvolutionaryGenerator};
+    use crate::field::FractalField;
+    use crate::filters::{
+        AnyTagFilter, CompositeFilter, DescriptionFilter, EntropyRangeFilter, FieldFilter,
+        FilterTrace, FractalPredicateFilter, LawFieldFilter, PredicateFilter,
+        ResonantFractalCollection, ScoreFieldFilter, TagPrefixFilter,
+    };
+    use crate::fractaledge::FractalEdge;
+    use crate::geometric::GeometricJitter;
+    use crate::graph::{EdgeType, FractalGraph, NodeId};
+    use crate::graphedge::{EntropyBounds, GraphEdge};
+    use crate::looprep::{LoopReport, ScoreStats};
+    use crate::mutation::{Identity, MutationSuite};
+    use crate::novelty::NoveltySearch;
+    use crate::registry::GeneratorRegistry;
+    use crate::resonance::{
+        aggregate_resonance, classify_phasor, dominant_law, AmplitudeScale, ComposedTransform, NullResonance,
+        PhaseShift, Resonance, ResonanceFilter, ResonanceLaw, ResonantTransform,
+        TransformResonanceLaw,
+    };
+    use crate::rfg::RandomFieldGenerator;
+    use crate::signature::{FractalSignature, SignatureFeature, SignatureHistogram};
+    use crate::stochastic::StochasticAmplitudePhase;
+    use crate::testkit::canonical_test_fractal;
+    use crate::time::{Evolvable, FractalSpacetime, SpacetimeCoordinate};
+    use crate::traits::{
+        count_nodes, fold_fractal, AffineIFS, CoherenceCritic, CollectionMember, Critic,
+        EntropyCritic, FieldClass, Fractal, FractalCollection, FractalType, Generator,
+        MutationStrategy, Operation, SimplicityCritic, SymmetryCritic, TransformDeltaCritic,
+        VectorSpace, IFS, Julia, Mandelbrot,
+    };
+    use crate::vec3::Vec3;
+    use crate::zmod::Zmod;
+    use num_complex::Complex;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::f32::consts::PI;
 
     #[test]
     fn new_empty_collection_returns_error() {
@@ -73,4 +125,2330 @@ mod tests {
         let collected: Vec<String> = tags.iter().cloned().collect();
         assert_eq!(collected, vec!["alpha", "beta", "zeta"]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn field_iterates_by_reference_and_by_value() {
+        let field = canonical_test_fractal();
+        assert_eq!(field.len(), 3);
+        assert!(!field.is_empty());
+
+        let directions: Vec<Vec3> = (&field).into_iter().map(|e| e.direction).collect();
+        assert_eq!(directions, vec![Vec3::X, Vec3::Y, Vec3::Z]);
+
+        let owned: Vec<GraphEdge> = field.clone().into_iter().collect();
+        assert_eq!(owned, field.edges);
+    }
+
+    #[test]
+    fn field_index_returns_edge_reference() {
+        let field = canonical_test_fractal();
+        assert_eq!(field[1].direction, Vec3::Y);
+        assert_eq!(&field[2], &field.edges[2]);
+    }
+
+    #[test]
+    fn concat_preserves_all_edges() {
+        let a = canonical_test_fractal();
+        let b = FractalField::one();
+        let combined = a.concat(&b);
+        assert_eq!(combined.len(), a.len() + b.len());
+        assert_eq!(combined.edges[..3], a.edges[..]);
+        assert_eq!(combined[3], b[0]);
+    }
+
+    #[test]
+    fn push_edge_appends_to_field() {
+        let mut field = FractalField::zero();
+        let edge = canonical_test_fractal()[0];
+        field.push_edge(edge);
+        assert_eq!(field.len(), 1);
+        assert_eq!(field[0], edge);
+    }
+
+    #[test]
+    fn tagset_union_merges_sorted_and_unique() {
+        let a = TagSet::new(vec!["beta", "alpha"]).unwrap();
+        let b = TagSet::new(vec!["gamma", "beta"]).unwrap();
+        let collected: Vec<String> = a.union(&b).into_iter().collect();
+        assert_eq!(collected, vec!["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn tagset_intersection_and_difference() {
+        let a = TagSet::new(vec!["alpha", "beta", "gamma"]).unwrap();
+        let b = TagSet::new(vec!["beta", "delta"]).unwrap();
+        assert_eq!(a.intersection(&b), TagSet::new(vec!["beta"]));
+        assert_eq!(a.difference(&b), TagSet::new(vec!["alpha", "gamma"]));
+    }
+
+    #[test]
+    fn tagset_empty_intersection_returns_error() {
+        let a = TagSet::new(vec!["alpha"]).unwrap();
+        let b = TagSet::new(vec!["beta"]).unwrap();
+        assert_eq!(a.intersection(&b), Err(TagSetError::EmptyCollection));
+        assert_eq!(a.difference(&a), Err(TagSetError::EmptyCollection));
+    }
+
+    #[test]
+    fn tagset_insert_preserves_sort_order() {
+        let mut tags = TagSet::new(vec!["alpha", "gamma"]).unwrap();
+        assert_eq!(tags.insert(" beta "), Ok(true));
+        let collected: Vec<String> = tags.iter().cloned().collect();
+        assert_eq!(collected, vec!["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn tagset_insert_duplicate_and_empty() {
+        let mut tags = TagSet::new(vec!["alpha"]).unwrap();
+        assert_eq!(tags.insert("alpha"), Ok(false));
+        assert_eq!(tags.insert("  "), Err(TagSetError::EmptyTag("".to_string())));
+        assert_eq!(tags.len(), 1);
+    }
+
+    #[test]
+    fn tagset_remove_last_tag_is_refused() {
+        let mut tags = TagSet::new(vec!["alpha", "beta"]).unwrap();
+        assert_eq!(tags.remove("missing"), Ok(false));
+        assert_eq!(tags.remove("alpha"), Ok(true));
+        assert_eq!(tags.remove("beta"), Err(TagSetError::LastTag("beta".to_string())));
+        assert!(tags.contains("beta"));
+    }
+
+    fn edge(amplitude: f32, phase: f32) -> FractalEdge {
+        FractalEdge { amplitude: Complex::new(amplitude, 0.0), location: 0, phase }
+    }
+
+    #[test]
+    fn aggregate_resonance_is_mean_score() {
+        let a = edge(1.0, PI);
+        let b = edge(3.0, PI);
+        let items: Vec<&dyn Resonance> = vec![&a, &b];
+        let expected = (a.resonance_score() + b.resonance_score()) / 2.0;
+        assert!((aggregate_resonance(&items) - expected).abs() < 1e-9);
+        assert_eq!(aggregate_resonance(&[]), 0.0);
+    }
+
+    #[test]
+    fn dominant_law_picks_most_frequent() {
+        let harmony = edge(1.0, 0.0);
+        let dissonance = edge(1.0, PI);
+        let null = edge(0.0, 0.0);
+        let items: Vec<&dyn Resonance> = vec![&dissonance, &harmony, &null, &harmony];
+        assert_eq!(dominant_law(&items), ResonanceLaw::Harmony);
+        assert_eq!(dominant_law(&[]), ResonanceLaw::Null);
+    }
+
+    #[test]
+    fn composed_transform_applies_chain_in_order() {
+        let input = edge(1.0, PI / 2.0);
+        let pipeline = ComposedTransform::new()
+            .then(PhaseShift { delta: PI / 2.0 })
+            .then(AmplitudeScale { factor: 2.0 });
+
+        let output = pipeline.apply(&input);
+        assert!((output.phase - PI).abs() < 1e-6);
+        assert_eq!(output.amplitude, Complex::new(2.0, 0.0));
+
+        // Score goes from 1 * |1 - cos(π/2)| = 1 to 2 * |1 - cos(π)| = 4.
+        assert!((pipeline.resonance_delta(&input) - 3.0).abs() < 1e-5);
+        assert_eq!(pipeline.transform_law(&input), TransformResonanceLaw::Amplifying);
+    }
+
+    #[test]
+    fn empty_composed_transform_is_identity() {
+        let input = edge(1.5, 0.3);
+        let pipeline: ComposedTransform<FractalEdge> = ComposedTransform::new();
+        assert_eq!(pipeline.apply(&input), input);
+        assert_eq!(pipeline.transform_law(&input), TransformResonanceLaw::Invariant);
+    }
+
+    #[test]
+    fn amplitude_scale_above_one_is_amplifying() {
+        let input = edge(1.0, PI / 2.0);
+        let transform = AmplitudeScale { factor: 2.0 };
+        assert_eq!(transform.apply(&input).amplitude, Complex::new(2.0, 0.0));
+        assert_eq!(transform.transform_law(&input), TransformResonanceLaw::Amplifying);
+    }
+
+    #[test]
+    fn amplitude_scale_below_one_is_dampening() {
+        let input = edge(1.0, PI / 2.0);
+        let transform = AmplitudeScale { factor: 0.5 };
+        assert_eq!(transform.apply(&input).amplitude, Complex::new(0.5, 0.0));
+        assert_eq!(transform.transform_law(&input), TransformResonanceLaw::Dampening);
+    }
+
+    /// A `Default` type that previously collided with the blanket `Resonance` impl.
+    #[derive(Default)]
+    struct Tuning {
+        pitch: f64,
+    }
+
+    impl Resonance for Tuning {
+        fn as_any(&self) -> &dyn std::any::Any { self }
+        fn resonance_score(&self) -> f64 { self.pitch }
+        fn resonance_similarity(&self, _other: &dyn Resonance) -> f64 { 0.0 }
+        fn resonance_law(&self) -> ResonanceLaw { ResonanceLaw::Harmony }
+    }
+
+    #[test]
+    fn default_types_can_implement_resonance() {
+        let tuning = Tuning { pitch: 440.0 };
+        assert_eq!(tuning.resonance_score(), 440.0);
+        assert_eq!(Tuning::default().resonance_law(), ResonanceLaw::Harmony);
+    }
+
+    #[test]
+    fn null_resonance_wrapper_is_non_resonant() {
+        let wrapped = NullResonance(42u32);
+        assert_eq!(wrapped.resonance_score(), 0.0);
+        assert_eq!(wrapped.resonance_law(), ResonanceLaw::Null);
+        assert_eq!(wrapped.resonance_similarity(&edge(1.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn map_nodes_preserves_topology() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let a = graph.add_node(1);
+        let b = graph.add_node(2);
+        graph.add_edge(a, b, EdgeType::Resonant, Complex::new(0.5, 0.5)).unwrap();
+
+        let mut mapped: FractalGraph<String> = graph.map_nodes(|v| format!("node-{}", v));
+        assert_eq!(mapped.node_count(), 2);
+        assert_eq!(mapped.edge_count(), 1);
+        assert_eq!(mapped.get_node(a).unwrap().payload, "node-1");
+        assert_eq!(mapped.get_node(b).unwrap().payload, "node-2");
+
+        let edges = mapped.get_edges_for_node_mut(a).unwrap();
+        assert_eq!(edges[0].destination, b);
+        assert_eq!(edges[0].weight, Complex::new(0.5, 0.5));
+
+        // New nodes must not reuse existing IDs.
+        assert_ne!(mapped.add_node("node-3".to_string()), b);
+    }
+
+    #[test]
+    fn map_edge_weights_updates_every_edge() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let a = graph.add_node(1);
+        let b = graph.add_node(2);
+        graph.add_edge(a, b, EdgeType::Excitatory, Complex::new(1.0, 0.0)).unwrap();
+        graph.add_edge(b, a, EdgeType::Inhibitory, Complex::new(0.0, 2.0)).unwrap();
+
+        let mut doubled = graph.with_edge_weights(|w| w * 2.0);
+        graph.map_edge_weights(|w| w * 0.5);
+
+        assert_eq!(graph.get_edges_for_node_mut(a).unwrap()[0].weight, Complex::new(0.5, 0.0));
+        assert_eq!(graph.get_edges_for_node_mut(b).unwrap()[0].weight, Complex::new(0.0, 1.0));
+        assert_eq!(doubled.get_edges_for_node_mut(a).unwrap()[0].weight, Complex::new(2.0, 0.0));
+        assert_eq!(doubled.get_edges_for_node_mut(b).unwrap()[0].weight, Complex::new(0.0, 4.0));
+    }
+
+    #[test]
+    fn spectral_radius_of_two_node_cycle() {
+        // The adjacency matrix [[0, 2], [2, 0]] has eigenvalues ±2.
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let a = graph.add_node(0);
+        let b = graph.add_node(1);
+        graph.add_edge(a, b, EdgeType::Resonant, Complex::new(2.0, 0.0)).unwrap();
+        graph.add_edge(b, a, EdgeType::Resonant, Complex::new(2.0, 0.0)).unwrap();
+        assert!((graph.spectral_radius(50) - 2.0).abs() < 1e-3);
+
+        // A disconnected, isolated node does not change the dominant eigenvalue.
+        graph.add_node(2);
+        assert!((graph.spectral_radius(50) - 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn spectral_radius_of_acyclic_graph_is_zero() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let a = graph.add_node(0);
+        let b = graph.add_node(1);
+        graph.add_edge(a, b, EdgeType::Excitatory, Complex::new(3.0, 0.0)).unwrap();
+        assert_eq!(graph.spectral_radius(10), 0.0);
+        assert_eq!(FractalGraph::<i32>::new().spectral_radius(10), 0.0);
+    }
+
+    #[test]
+    fn tick_moves_minkowski_particle_by_velocity() {
+        let mut spacetime = FractalSpacetime {
+            coordinate: SpacetimeCoordinate::Minkowski(0.0, 0.0, 0.0, 0.0),
+            velocity: [0.6, 0.0, 0.0],
+        };
+        for _ in 0..5 {
+            spacetime.tick();
+        }
+
+        match spacetime.coordinate {
+            SpacetimeCoordinate::Minkowski(t, x, y, z) => {
+                assert_eq!(t, 5.0);
+                assert!((x - 3.0).abs() < 1e-9);
+                assert_eq!((y, z), (0.0, 0.0));
+            }
+            _ => panic!("expected a Minkowski coordinate"),
+        }
+        // sqrt(5² - 3²) = 4: the moving particle's clock runs slow.
+        assert!((spacetime.proper_time() - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn proper_time_is_zero_when_not_timelike() {
+        let spacetime = FractalSpacetime::new(SpacetimeCoordinate::Minkowski(1.0, 2.0, 0.0, 0.0));
+        assert_eq!(spacetime.proper_time(), 0.0);
+    }
+
+    #[test]
+    fn zero_dimensional_spacetime_has_no_proper_time() {
+        let mut spacetime = FractalSpacetime::new(SpacetimeCoordinate::from_components(vec![]));
+        assert_eq!(spacetime.coordinate.dimension(), 0);
+        assert_eq!(spacetime.proper_time(), 0.0);
+
+        // Growing out of zero dimensions releases the waveform of a state at rest.
+        let energy = spacetime.transition(4);
+        assert_eq!(energy, vec![0.0, 1.0, 0.0, 1.0]); // 4 dimensions gained -> 2 harmonics.
+        assert_eq!(spacetime.coordinate.components(), vec![0.0; 4]);
+        assert!(spacetime.transition(0).is_empty());
+    }
+
+    #[test]
+    fn transition_from_sequential_to_4d() {
+        let mut spacetime = FractalSpacetime::new(SpacetimeCoordinate::Sequential(2.0));
+        let energy = spacetime.transition_to_4d();
+        assert_eq!(energy, vec![2.0_f64.sin(), 2.0_f64.cos()]);
+        assert_eq!(spacetime.coordinate.components(), vec![0.0; 4]);
+        assert!(matches!(spacetime.coordinate, SpacetimeCoordinate::Minkowski(..)));
+    }
+
+    #[test]
+    fn transition_to_current_dimension_is_noop() {
+        let mut spacetime = FractalSpacetime::new(SpacetimeCoordinate::Minkowski(1.0, 2.0, 3.0, 4.0));
+        assert!(spacetime.transition(4).is_empty());
+        assert_eq!(spacetime.coordinate.components(), vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn transition_from_4d_to_higher_keeps_components() {
+        let mut spacetime = FractalSpacetime::new(SpacetimeCoordinate::Minkowski(1.0, 2.0, 3.0, 4.0));
+        let energy = spacetime.transition(11);
+        assert_eq!(energy.len(), 6); // 7 dimensions gained -> 3 harmonics.
+        assert_eq!(spacetime.coordinate.dimension(), 11);
+        assert_eq!(spacetime.coordinate.components()[..4], [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn gaussian_pdf_peaks_at_mean() {
+        let g = Gaussian { mean: 3.0, std_dev: 2.0 };
+        let peak = g.pdf(3.0);
+        assert!((peak - 1.0 / (2.0 * (2.0 * std::f64::consts::PI).sqrt())).abs() < 1e-12);
+        assert!(peak > g.pdf(2.5) && peak > g.pdf(3.5));
+        assert!((g.pdf(1.0) - g.pdf(5.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gaussian_cdf_is_half_at_mean() {
+        let g = Gaussian { mean: -1.0, std_dev: 0.5 };
+        assert!((g.cdf(-1.0) - 0.5).abs() < 1e-6);
+        // One standard deviation above the mean covers ~84.13% of the mass.
+        assert!((g.cdf(-0.5) - 0.841_344_7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn gaussian_samples_centre_on_mean() {
+        let g = Gaussian { mean: 10.0, std_dev: 1.0 };
+        let mut rng = rand::rng();
+        let n = 2000;
+        let mean = (0..n).map(|_| g.sample(&mut rng)).sum::<f64>() / n as f64;
+        assert!((mean - 10.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn bayesian_update_shrinks_variance_toward_observation() {
+        let mut beliefs = FrequencyBeliefSpace::new(100.0, 1.0);
+        let mut previous_std_dev = beliefs.frequency.std_dev;
+        for _ in 0..20 {
+            beliefs.bayesian_update(440.0, 25.0);
+            assert!(beliefs.frequency.std_dev < previous_std_dev);
+            previous_std_dev = beliefs.frequency.std_dev;
+        }
+        assert!((beliefs.frequency.mean - 440.0).abs() < 5.0);
+        // After 20 observations with variance 25, the posterior variance is below 25 / 20.
+        assert!(beliefs.frequency.std_dev.powi(2) < 25.0 / 20.0);
+    }
+
+    #[test]
+    fn frequency_belief_space_update_refines_only_frequency() {
+        let mut beliefs = FrequencyBeliefSpace::new(100.0, 1.0);
+        let guess = beliefs.propose_best_guess();
+        assert_eq!((guess.waveform.as_str(), guess.phase), ("sine", 0.0));
+
+        let better = EntropyPulse { frequency: 200.0, amplitude: 3.0, ..guess };
+        beliefs.update(&FeedbackSignal { correlation_strength: 0.5 }, &better);
+        assert_eq!(beliefs.best_guess.frequency, 200.0);
+        assert_eq!(beliefs.best_feedback.correlation_strength, 0.5);
+        // The frequency belief moves 15% of the way to the best guess and narrows by 0.9.
+        assert!((beliefs.frequency.mean - 115.0).abs() < 1e-9);
+        assert!((beliefs.frequency.std_dev - 45.0).abs() < 1e-9);
+        assert_eq!((beliefs.amplitude.mean, beliefs.amplitude.std_dev), (1.0, 1.0));
+    }
+
+    #[test]
+    fn belief_space_converges_on_all_dimensions() {
+        // A synthetic objective with a known optimum in every dimension.
+        let error = |p: &EntropyPulse| {
+            (p.frequency - 300.0).abs() / 50.0
+                + (p.amplitude - 2.0).abs()
+                + (p.phase - 1.0).abs()
+                + if p.waveform == "square" { 0.0 } else { 1.0 }
+        };
+
+        let initial = EntropyPulse {
+            frequency: 280.0,
+            amplitude: 1.5,
+            waveform: "sine".to_string(),
+            phase: 0.5,
+        };
+        let waveforms = vec!["sine".to_string(), "square".to_string(), "triangle".to_string()];
+        let mut beliefs = BeliefSpace::new(initial, &BeliefSpace::ALL_DIMENSIONS, waveforms);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..300 {
+            let guess = beliefs.propose_with_rng(&mut rng);
+            let feedback = FeedbackSignal { correlation_strength: error(&guess) };
+            beliefs.update(&feedback, &guess);
+        }
+
+        assert_eq!(beliefs.best_guess.waveform, "square");
+        assert!((beliefs.belief(BeliefSpace::FREQUENCY).unwrap().mean - 300.0).abs() < 30.0);
+        assert!((beliefs.belief(BeliefSpace::AMPLITUDE).unwrap().mean - 2.0).abs() < 0.5);
+        assert!((beliefs.belief(BeliefSpace::PHASE).unwrap().mean - 1.0).abs() < 0.5);
+        let total: f64 = beliefs.waveforms.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn belief_space_keeps_unsearched_dimensions_fixed() {
+        let initial = EntropyPulse { frequency: 280.0, amplitude: 1.5, waveform: "sine".to_string(), phase: 0.5 };
+        let beliefs = BeliefSpace::new(initial, &[BeliefSpace::FREQUENCY], vec!["sine".to_string()]);
+        assert!(beliefs.belief(BeliefSpace::AMPLITUDE).is_none());
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let guess = beliefs.propose_with_rng(&mut rng);
+        assert_ne!(guess.frequency, 280.0);
+        assert_eq!((guess.amplitude, guess.phase), (1.5, 0.5));
+    }
+
+    #[test]
+    fn simulated_annealing_minimizes_quadratic() {
+        let error = |p: &EntropyPulse| (p.frequency - 300.0).powi(2) / 100.0;
+
+        let initial = EntropyPulse {
+            frequency: 250.0,
+            amplitude: 1.0,
+            waveform: "sine".to_string(),
+            phase: 0.0,
+        };
+        let mut search = SimulatedAnnealing::new(initial, 10.0, 0.995);
+        let mut rng = StdRng::seed_from_u64(11);
+
+        for _ in 0..1000 {
+            let guess = search.propose_with_rng(&mut rng);
+            let feedback = FeedbackSignal { correlation_strength: error(&guess) };
+            search.update_with_rng(&feedback, &guess, &mut rng);
+        }
+
+        // The error is 0 at the 300 Hz minimum, against 25 at the 250 Hz start.
+        assert!((search.best_guess.frequency - 300.0).abs() < 1.0);
+        assert!(search.best_feedback.correlation_strength < 0.01);
+        assert!(search.temperature < 1.0);
+    }
+
+    fn pulse(frequency: f64, amplitude: f64) -> EntropyPulse {
+        EntropyPulse { frequency, amplitude, waveform: "sine".to_string(), phase: 0.0 }
+    }
+
+    #[test]
+    fn band_constraint_accepts_in_band_pulses() {
+        let band = BandConstraint { min_freq: 100.0, max_freq: 200.0, min_amp: 0.5, max_amp: 2.0 };
+        assert!(band.is_valid(&pulse(150.0, 1.0)));
+        assert!(band.is_valid(&pulse(100.0, 2.0)));
+    }
+
+    #[test]
+    fn band_constraint_rejects_out_of_band_pulses() {
+        let band = BandConstraint { min_freq: 100.0, max_freq: 200.0, min_amp: 0.5, max_amp: 2.0 };
+        assert!(!band.is_valid(&pulse(99.9, 1.0)));
+        assert!(!band.is_valid(&pulse(250.0, 1.0)));
+        assert!(!band.is_valid(&pulse(150.0, 0.1)));
+    }
+
+    #[test]
+    fn propose_valid_resamples_into_band() {
+        let beliefs = FrequencyBeliefSpace::new(150.0, 1.0);
+        let band = BandConstraint { min_freq: 100.0, max_freq: 200.0, min_amp: -1.0, max_amp: 3.0 };
+        for _ in 0..20 {
+            assert!(band.is_valid(&beliefs.propose_valid(&band)));
+        }
+    }
+
+    /// Builds a single-edge field whose signature entropy is `amplitude * |phase|`.
+    fn field_with_entropy(amplitude: f32, phase: f32) -> FractalField {
+        let mut field = FractalField::one();
+        field.edges[0].data = Complex::from_polar(amplitude, phase);
+        field
+    }
+
+    #[test]
+    fn signature_histogram_counts_and_mode() {
+        // Entropies: 0, 1, 1, 1, 4 spread over four bins of width 1.
+        let fields: Vec<FractalField> = [0.0, 1.0, 1.0, 1.0, 4.0]
+            .iter()
+            .map(|e| field_with_entropy(*e, 1.0))
+            .collect();
+
+        let histogram = SignatureHistogram::from_fields(&fields, 4);
+        assert_eq!(histogram.counts, vec![1, 3, 0, 1]);
+        assert_eq!(histogram.mode(), 1);
+        let (lower, upper) = histogram.bin_range(1);
+        assert!((lower - 1.0).abs() < 1e-5 && (upper - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn signature_histogram_of_collapsed_population() {
+        let fields = vec![FractalField::one(); 5];
+        let histogram = SignatureHistogram::from_fields_by(&fields, 3, SignatureFeature::TotalAmplitude);
+        assert_eq!(histogram.counts, vec![5, 0, 0]);
+        assert_eq!(histogram.mode(), 0);
+    }
+
+    #[test]
+    fn cluster_fields_recovers_separated_groups() {
+        // Two groups with very different total amplitudes, interleaved in the input.
+        let fields: Vec<FractalField> = [1.0, 50.0, 1.2, 51.0, 0.9, 49.5]
+            .iter()
+            .map(|a| field_with_entropy(*a, 0.5))
+            .collect();
+
+        let labels = cluster_fields(&fields, 2, 10);
+        assert_eq!(labels.len(), 6);
+        assert_eq!(labels[0], labels[2]);
+        assert_eq!(labels[0], labels[4]);
+        assert_eq!(labels[1], labels[3]);
+        assert_eq!(labels[1], labels[5]);
+        assert_ne!(labels[0], labels[1]);
+    }
+
+    #[test]
+    fn uniform_population_has_no_diversity() {
+        let fields = vec![canonical_test_fractal(); 10];
+        assert!(population_diversity(&fields).abs() < 1e-6);
+        assert_eq!(population_diversity(&fields[..1]), 0.0);
+    }
+
+    #[test]
+    fn varied_population_has_positive_diversity() {
+        let fields: Vec<FractalField> = [0.5, 2.0, 8.0, 20.0]
+            .iter()
+            .map(|a| field_with_entropy(*a, 1.0))
+            .collect();
+        let uniform = vec![fields[0].clone(); 4];
+        assert!(population_diversity(&fields) > population_diversity(&uniform) + 1.0);
+    }
+
+    /// A deterministic generator offering a "stay" candidate and a distant "leap" candidate.
+    struct StayOrLeapGenerator;
+
+    impl Generator for StayOrLeapGenerator {
+        fn generate(&self) -> Vec<FractalField> {
+            vec![FractalField::one()]
+        }
+
+        fn mutate(&self, field: &FractalField) -> Vec<FractalField> {
+            vec![field.clone(), field.clone() * Complex::new(3.0, 0.0)]
+        }
+    }
+
+    /// Prefers fields whose total amplitude is close to one.
+    struct UnitAmplitudeCritic;
+
+    impl Critic for UnitAmplitudeCritic {
+        fn score(&self, field: &FractalField) -> f32 {
+            -(field.signature().total_amplitude - 1.0).abs()
+        }
+    }
+
+    fn niching_loop(diversity_weight: f32) -> GeneratorCriticLoop<StayOrLeapGenerator> {
+        let mut critic_suite = CriticSuite::new();
+        critic_suite.add_critic(UnitAmplitudeCritic, 1.0);
+        GeneratorCriticLoopBuilder::new()
+            .generator(StayOrLeapGenerator)
+            .critics(critic_suite)
+            .iterations(5)
+            .diversity_weight(diversity_weight)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn diversity_weight_prevents_collapse_onto_incumbent() {
+        let collapsed = niching_loop(0.0).run_with_report().unwrap();
+        let niched = niching_loop(2.0).run_with_report().unwrap();
+
+        let trajectory = |report: &LoopReport| -> Vec<FractalField> {
+            report.history.iter().map(|(f, _)| f.clone()).collect()
+        };
+        assert_eq!(population_diversity(&trajectory(&collapsed)), 0.0);
+        // Niching explores the leap every generation without giving up the better incumbent.
+        assert!(population_diversity(&trajectory(&niched)) > 0.0);
+        assert!(niched.history[1..].iter().all(|(f, _)| *f != FractalField::one()));
+        assert_eq!(collapsed.best_field, FractalField::one());
+        assert_eq!(niched.best_field, FractalField::one());
+    }
+
+    #[test]
+    fn niching_never_replaces_a_better_incumbent() {
+        // The distant leap wins selection, but it scores worse than the optimal seed.
+        let loop_engine = niching_loop(2.0);
+        assert_eq!(loop_engine.run(), Some(FractalField::one()));
+        let report = loop_engine.run_with_report().unwrap();
+        assert_eq!(report.best_score, 0.0);
+        assert!(report.history[1..].iter().all(|(_, score)| *score == -2.0));
+        assert_eq!(loop_engine.run_with_full_report().unwrap().best_field, FractalField::one());
+    }
+
+    #[test]
+    fn full_report_records_every_generation() {
+        let report = niching_loop(0.0).run_with_full_report().unwrap();
+        assert_eq!(report.generations.len(), 5);
+        // The seed generation has one field; every mutation generation has two.
+        assert_eq!(report.generations[0].len(), 1);
+        assert!(report.generations[1..].iter().all(|g| g.len() == 2));
+        assert_eq!(report.best_field, FractalField::one());
+        assert_eq!(report.generations[1][1].1, -2.0);
+    }
+
+    #[test]
+    fn crossover_of_identical_parents_is_the_parent() {
+        let parent = canonical_test_fractal();
+        let mut rng = rand::rng();
+        assert_eq!(Crossover::crossover(&parent, &parent, &mut rng), parent);
+    }
+
+    #[test]
+    fn crossover_data_comes_only_from_parents() {
+        let a = canonical_test_fractal();
+        let b = a.clone() * Complex::new(0.0, 2.0);
+        let mut rng = rand::rng();
+        for _ in 0..20 {
+            let child = Crossover::crossover(&a, &b, &mut rng);
+            assert_eq!(child.len(), a.len());
+            for (i, edge) in child.iter().enumerate() {
+                assert!(edge.data == a[i].data || edge.data == b[i].data);
+                assert_eq!(edge.direction, a[i].direction);
+            }
+        }
+    }
+
+    #[test]
+    fn evolutionary_generator_recombines_with_secondary_parent() {
+        let secondary = canonical_test_fractal() * Complex::new(5.0, 0.0);
+        let generator = EvolutionaryGenerator {
+            mutations: MutationSuite::new(),
+            count: 50,
+            crossover_rate: 1.0,
+            secondary_parent: Some(secondary.clone()),
+            seed_count: 1,
+        };
+        let parent = canonical_test_fractal();
+        let children = generator.mutate(&parent);
+        assert!(children.iter().any(|c| c != &parent));
+        assert!(children.iter().all(|c| c.iter().enumerate().all(|(i, e)| {
+            e.data == parent[i].data || e.data == secondary[i].data
+        })));
+    }
+
+    #[test]
+    fn geometric_jitter_changes_geometry_but_preserves_data() {
+        let field = canonical_test_fractal();
+        let jitter = GeometricJitter { position_jitter: 0.5, length_jitter: 0.5, rotate_jitter: 0.3 };
+        let mutated = jitter.mutate(&field);
+
+        assert_eq!(mutated.len(), field.len());
+        assert_ne!(mutated, field);
+        for (before, after) in field.iter().zip(&mutated) {
+            assert_eq!(after.data, before.data);
+            assert_eq!(after.depth, before.depth);
+            assert!(after.length > 0.0);
+            assert!((after.direction.norm() - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn geometric_jitter_keeps_length_positive() {
+        let field = canonical_test_fractal();
+        let jitter = GeometricJitter { position_jitter: 0.0, length_jitter: 100.0, rotate_jitter: 0.0 };
+        for _ in 0..20 {
+            assert!(jitter.mutate(&field).iter().all(|e| e.length > 0.0));
+        }
+    }
+
+    #[test]
+    fn depth_mutation_shifts_depths() {
+        let mut field = canonical_test_fractal();
+        field.edges.iter_mut().for_each(|e| e.depth = 5);
+        let mutation = DepthMutation { max_delta: 2, spawn_probability: 0.0 };
+
+        let mut changed = false;
+        for _ in 0..20 {
+            let mutated = mutation.mutate(&field);
+            assert_eq!(mutated.len(), field.len());
+            assert!(mutated.iter().all(|e| (3..=7).contains(&e.depth)));
+            changed |= mutated.iter().any(|e| e.depth != 5);
+        }
+        assert!(changed);
+    }
+
+    #[test]
+    fn depth_mutation_spawns_child_at_endpoint() {
+        let field = FractalField::one();
+        let mutation = DepthMutation { max_delta: 0, spawn_probability: 1.0 };
+        let mutated = mutation.mutate(&field);
+
+        assert_eq!(mutated.len(), 2);
+        let (parent, child) = (mutated[0], mutated[1]);
+        assert_eq!(child.depth, parent.depth + 1);
+        assert_eq!(child.origin, parent.endpoint());
+        assert_eq!(child.length, parent.length * 0.5);
+    }
+
+    #[test]
+    fn depth_mutation_saturates_at_maximum_depth() {
+        let mut field = FractalField::one();
+        field.edges[0].depth = u32::MAX;
+        let mutation = DepthMutation { max_delta: u32::MAX, spawn_probability: 1.0 };
+        for _ in 0..20 {
+            let mutated = mutation.mutate(&field);
+            // The spawned child is one level below its parent, unless that is already the deepest.
+            let (parent, child) = (mutated[0], mutated[1]);
+            assert_eq!(child.depth, parent.depth.saturating_add(1));
+        }
+
+        let mutation = DepthMutation { max_delta: 0, spawn_probability: 1.0 };
+        assert_eq!(mutation.mutate(&field)[1].depth, u32::MAX);
+    }
+
+    fn signature(average_phase: f32, entropy: f32, edge_count: usize, max_depth: u32) -> FractalSignature {
+        FractalSignature {
+            total_amplitude: 1.0,
+            average_phase,
+            entropy,
+            edge_count,
+            depth_range: (0, max_depth),
+        }
+    }
+
+    #[test]
+    fn field_class_taxonomy_from_signatures() {
+        let cases = [
+            (signature(0.0, 0.0, 2, 5), FieldClass::Sparse),
+            (signature(0.0, 0.0, 30, 0), FieldClass::DenseHarmonic),
+            (signature(PI, 0.0, 5, 4), FieldClass::DeepSymmetric),
+            (signature(0.0, 0.0, 5, 1), FieldClass::ShallowSymmetric),
+            (signature(1.0, 20.0, 5, 3), FieldClass::DeepChaotic),
+            (signature(1.0, 20.0, 30, 0), FieldClass::ShallowChaotic),
+            (signature(1.0, 2.0, 5, 6), FieldClass::DeepStructured),
+            (signature(1.0, 2.0, 5, 2), FieldClass::ShallowStructured),
+        ];
+        for (sig, expected) in cases {
+            assert_eq!(SymmetryCritic.classify(&sig), expected);
+        }
+    }
+
+    #[test]
+    fn field_class_display_labels() {
+        assert_eq!(FieldClass::DeepSymmetric.to_string(), "deep-symmetric");
+        assert_eq!(FieldClass::ShallowChaotic.to_string(), "shallow-chaotic");
+        assert_eq!(CriticSuite::new().classify(&FractalField::one()), FieldClass::Unclassified);
+    }
+
+    #[test]
+    fn aligned_edges_are_fully_coherent() {
+        let field = canonical_test_fractal();
+        let aligned = FractalField {
+            edges: field.iter().map(|e| GraphEdge { data: Complex::from_polar(2.0, 0.7), ..*e }).collect(),
+        };
+        assert!((aligned.phase_coherence() - 1.0).abs() < 1e-6);
+        assert!((CoherenceCritic.score(&aligned) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn evenly_spread_phases_are_incoherent() {
+        let edge = FractalField::one()[0];
+        let field = FractalField {
+            edges: (0..4)
+                .map(|k| GraphEdge { data: Complex::from_polar(1.0, k as f32 * PI / 2.0), ..edge })
+                .collect(),
+        };
+        assert!(field.phase_coherence() < 1e-6);
+        assert_eq!(FractalField::zero().phase_coherence(), 0.0);
+    }
+
+    fn field_of_phasors(phasors: &[(f32, f32)]) -> FractalField {
+        let edge = FractalField::one()[0];
+        FractalField {
+            edges: phasors
+                .iter()
+                .map(|(amp, phase)| GraphEdge { data: Complex::from_polar(*amp, *phase), ..edge })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn field_dominant_law_is_most_common() {
+        let field = field_of_phasors(&[(1.0, 0.0), (2.0, 0.05), (1.0, PI), (0.5, 0.0), (1.0, 1.5)]);
+        assert_eq!(field.dominant_law(), ResonanceLaw::Harmony);
+        assert_eq!(FractalField::zero().dominant_law(), ResonanceLaw::Null);
+    }
+
+    #[test]
+    fn dissonance_at_plus_and_minus_pi_agrees_across_sources() {
+        for phase in [PI, -PI] {
+            assert_eq!(classify_phasor(1.0, phase), ResonanceLaw::Dissonance);
+            assert_eq!(edge(1.0, phase).resonance_law(), ResonanceLaw::Dissonance);
+        }
+        // Phases are compared as angles, so just below a full turn is still near 0.
+        assert_eq!(classify_phasor(1.0, 2.0 * PI - 0.05), ResonanceLaw::Harmony);
+
+        // `arg()` can report π as -π; the field and its converted edge agree either way.
+        for data in [Complex::new(-1.0, 1e-9), Complex::new(-1.0, -1e-9)] {
+            let mut field = FractalField::one();
+            field.edges[0].data = data;
+            assert_eq!(field.dominant_law(), ResonanceLaw::Dissonance);
+            assert_eq!(FractalEdge::from(&field.edges[0]).resonance_law(), ResonanceLaw::Dissonance);
+        }
+    }
+
+    #[test]
+    fn field_dominant_law_ties_favour_amplitude() {
+        let field = field_of_phasors(&[(1.0, 0.0), (5.0, PI), (1.0, 0.0), (5.0, PI)]);
+        assert_eq!(field.dominant_law(), ResonanceLaw::Dissonance);
+    }
+
+    #[test]
+    fn graph_edge_converts_to_fractal_edge() {
+        let mut edge = canonical_test_fractal()[2];
+        edge.depth = 300;
+        let abstract_edge = FractalEdge::from(&edge);
+        assert_eq!(abstract_edge.amplitude, edge.data);
+        assert_eq!(abstract_edge.phase, edge.data.arg());
+        assert_eq!(abstract_edge.location, 300 % crate::MODULUS);
+    }
+
+    #[test]
+    fn fractal_edge_round_trip_preserves_data() {
+        let edge = canonical_test_fractal()[1];
+        let restored = GraphEdge::from_fractal_edge(&FractalEdge::from(&edge), edge.origin, edge.direction);
+        assert_eq!(restored.data, edge.data);
+        assert_eq!(restored.depth, edge.depth);
+        assert_eq!(restored, edge);
+
+        let far = FractalEdge { location: usize::MAX, ..FractalEdge::from(&edge) };
+        assert_eq!(GraphEdge::from_fractal_edge(&far, edge.origin, edge.direction).depth, u32::MAX);
+    }
+
+    #[test]
+    fn field_resonance_against_itself_and_scaled_copy() {
+        let field = canonical_test_fractal();
+        assert!((field.resonance_similarity(&field) - 1.0).abs() < 1e-6);
+
+        let scaled = field.clone() * Complex::new(3.0, 0.0);
+        let similarity = field.resonance_similarity(&scaled);
+        assert!(similarity > 0.5 && similarity < 1.0);
+        assert_eq!(field.resonance_similarity(&edge(1.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn field_resonance_score_sums_edges() {
+        let field = canonical_test_fractal();
+        let expected: f64 = field.iter().map(|e| FractalEdge::from(e).resonance_score()).sum();
+        assert!((Resonance::resonance_score(&field) - expected).abs() < 1e-9);
+        assert_eq!(Resonance::resonance_law(&field), field.dominant_law());
+    }
+
+    fn atom(value: u8, tags: &[&str], domain: &str, description: Option<&str>) -> FractalAtom<u8> {
+        let metadata = Metadata {
+            domain: domain.to_string(),
+            description: description.map(str::to_string),
+        };
+        FractalAtom::new(value, TagSet::new(tags.to_vec()).unwrap(), metadata).unwrap()
+    }
+
+    #[test]
+    fn merge_atoms_in_same_domain() {
+        let a = atom(2, &["wave"], "physics", Some("carrier"));
+        let b = atom(3, &["pulse", "wave"], "physics", None);
+        let merged = a.merge(&b, |x, y| x + y).unwrap();
+
+        assert_eq!(merged.value, 5);
+        assert_eq!(merged.metadata.domain, "physics");
+        assert_eq!(merged.metadata.description.as_deref(), Some("carrier"));
+        assert_eq!(merged.tags, TagSet::new(vec!["pulse", "wave"]).unwrap());
+    }
+
+    #[test]
+    fn merge_atoms_across_domains() {
+        let a = atom(2, &["wave"], "physics", Some("carrier"));
+        let b = atom(3, &["word"], "linguistics", Some("phoneme"));
+        let merged = a.merge(&b, |x, y| x * y).unwrap();
+
+        assert_eq!(merged.value, 6);
+        assert_eq!(merged.metadata.domain, "physics+linguistics");
+        assert_eq!(merged.metadata.description.as_deref(), Some("carrier; phoneme"));
+        assert_eq!(merged.tags.len(), 2);
+    }
+
+    #[test]
+    fn beneficial_phase_shift_scores_positive() {
+        // Each edge's score rises from |1 - cos(π/2)| = 1 to |1 - cos(π)| = 2.
+        let field = field_of_phasors(&[(1.0, PI / 2.0), (1.0, PI / 2.0)]);
+        let critic = TransformDeltaCritic { transform: PhaseShift { delta: PI / 2.0 } };
+        assert!((critic.score(&field) - 2.0).abs() < 1e-5);
+
+        let harmful = TransformDeltaCritic { transform: PhaseShift { delta: -PI / 2.0 } };
+        assert!(harmful.score(&field) < 0.0);
+    }
+
+    #[test]
+    fn field_convolve_matches_edge_convolution() {
+        let a = canonical_test_fractal();
+        let b = field_of_phasors(&[(2.0, 0.5), (0.5, -1.0), (1.5, 2.0)]);
+        let result = a.convolve(&b);
+        assert_eq!(result.len(), a.len().min(b.len()));
+
+        for ((r, ea), eb) in result.iter().zip(&a).zip(&b) {
+            // Geometry comes from `self`.
+            assert_eq!(r.origin, ea.origin);
+            assert_eq!(r.direction, ea.direction);
+            assert_eq!(r.length, ea.length);
+
+            let expected = FractalEdge::from(ea).convolve(&FractalEdge::from(eb));
+            let actual = FractalEdge::from(r);
+            assert!((actual.amplitude - expected.amplitude).norm() < 1e-5);
+            assert_eq!(actual.location, expected.location);
+        }
+
+        let mut deep = FractalField::one();
+        deep.edges[0].depth = u32::MAX - 1;
+        assert_eq!(deep.convolve(&deep)[0].depth, u32::MAX);
+    }
+
+    #[test]
+    fn zmod_arithmetic_wraps_around_modulus() {
+        assert_eq!(Zmod::new(300).value(), 300 % MODULUS);
+        assert_eq!(Zmod::new(200) + Zmod::new(100), Zmod::new(44));
+        assert_eq!(Zmod::new(3) - Zmod::new(5), Zmod::new(MODULUS - 2));
+        assert_eq!(Zmod::new(20) * Zmod::new(20), Zmod::new(400 - MODULUS));
+        assert_eq!(-Zmod::ZERO, Zmod::ZERO);
+        assert_eq!(Zmod::new(7) + -Zmod::new(7), Zmod::ZERO);
+    }
+
+    #[test]
+    fn zmod_inverse_exists_only_for_units() {
+        for value in (1..MODULUS).step_by(2) {
+            let x = Zmod::new(value);
+            assert_eq!(x * x.inverse().unwrap(), Zmod::ONE);
+        }
+        // 256 is not prime: zero and even residues share a factor with it.
+        assert_eq!(Zmod::ZERO.inverse(), None);
+        assert_eq!(Zmod::new(2).inverse(), None);
+        assert_eq!(Zmod::new(128).inverse(), None);
+    }
+
+    #[test]
+    fn convolve_mod_wraps_location_by_modulus() {
+        let a = FractalEdge { location: 5, ..edge(2.0, 0.5) };
+        let b = FractalEdge { location: 4, ..edge(3.0, 0.25) };
+
+        let small = a.convolve_mod(&b, 7);
+        assert_eq!(small.location, 2);
+        assert_eq!(small.amplitude, Complex::new(6.0, 0.0));
+        assert_eq!(small.phase, 0.75);
+
+        let far = FractalEdge { location: 1000, ..edge(1.0, 0.0) };
+        assert_eq!(far.convolve_mod(&b, 1024).location, 1004);
+        assert_eq!(far.convolve_mod(&far, 1024).location, 2000 - 1024);
+
+        // The plain convolution still wraps at the global modulus.
+        assert_eq!(far.convolve(&b), far.convolve_mod(&b, MODULUS));
+    }
+
+    #[test]
+    fn evolutionary_generator_generates_seed_count_fields() {
+        let mut mutations = MutationSuite::new();
+        mutations.add_strategy(StochasticAmplitudePhase { amplitude_jitter: 0.5, phase_jitter: 0.5 }, 1.0);
+        let mut generator = EvolutionaryGenerator {
+            mutations,
+            count: 1,
+            crossover_rate: 0.0,
+            secondary_parent: None,
+            seed_count: 8,
+        };
+        let seeds = generator.generate();
+        assert_eq!(seeds.len(), 8);
+        assert_eq!(seeds[0], FractalField::one());
+        assert!(seeds[1..].iter().any(|s| s != &FractalField::one()));
+
+        generator.seed_count = 1;
+        assert_eq!(generator.generate(), vec![FractalField::one()]);
+    }
+
+    #[test]
+    fn generic_evolutionary_generator_runs_in_loop() {
+        struct TargetAmplitudeCritic;
+        impl Critic for TargetAmplitudeCritic {
+            fn score(&self, field: &FractalField) -> f32 {
+                -(field.signature().total_amplitude - 3.0).abs()
+            }
+        }
+
+        let generator = GenericEvolutionaryGenerator {
+            strategy: StochasticAmplitudePhase { amplitude_jitter: 0.5, phase_jitter: 0.1 },
+            count: 10,
+        };
+        assert_eq!(generator.mutate(&FractalField::one()).len(), 10);
+
+        let mut critic_suite = CriticSuite::new();
+        critic_suite.add_critic(TargetAmplitudeCritic, 1.0);
+        let loop_engine =
+            GeneratorCriticLoopBuilder::new().generator(generator).critics(critic_suite).iterations(30).build().unwrap();
+
+        let best = loop_engine.run().unwrap();
+        // The identity seed starts at amplitude 1.0, two away from the target.
+        assert!((best.signature().total_amplitude - 3.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn identity_mutation_preserves_field() {
+        let field = canonical_test_fractal();
+        assert_eq!(Identity.mutate(&field), field);
+
+        let mut suite = MutationSuite::new();
+        suite.add_strategy(Identity, 1.0);
+        assert_eq!(suite.mutate(&field), field);
+    }
+
+    #[test]
+    fn critic_suite_breakdown_sums_to_score() {
+        let mut suite = CriticSuite::new();
+        suite.add_critic(SymmetryCritic, 0.7);
+        suite.add_critic(EntropyCritic, 0.3);
+        suite.add_critic(CoherenceCritic, 2.0);
+
+        let field = canonical_test_fractal();
+        let breakdown = suite.score_breakdown(&field);
+        assert_eq!(breakdown.len(), 3);
+        assert_eq!(breakdown[0].0, "SymmetryCritic");
+        assert_eq!(breakdown[2].0, "CoherenceCritic");
+        assert!((breakdown[1].2 - breakdown[1].1 * 0.3).abs() < 1e-6);
+
+        let total: f32 = breakdown.iter().map(|(_, _, contribution)| contribution).sum();
+        assert!((total - suite.score(&field)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn critics_report_their_names() {
+        assert_eq!(SymmetryCritic.name(), "SymmetryCritic");
+        assert_eq!(EntropyCritic.name(), "EntropyCritic");
+        assert_eq!(UnitAmplitudeCritic.name(), "UnitAmplitudeCritic");
+    }
+
+    #[test]
+    fn graph_to_dot_lists_nodes_and_edges() {
+        let mut graph: FractalGraph<&str> = FractalGraph::new();
+        let a = graph.add_node("alpha");
+        let b = graph.add_node("beta");
+        let c = graph.add_node("gamma");
+        graph.add_edge(a, b, EdgeType::Excitatory, Complex::new(1.0, 0.0)).unwrap();
+        graph.add_edge(b, c, EdgeType::Inhibitory, Complex::new(-2.0, 0.0)).unwrap();
+        graph.add_edge(c, a, EdgeType::Resonant, Complex::new(0.0, 0.5)).unwrap();
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph"));
+        assert_eq!(dot.matches("[label=").count(), 3);
+        assert_eq!(dot.matches(" -> ").count(), 3);
+        assert!(dot.contains("[label=\"beta\"]"));
+        assert!(dot.contains("color=green, style=solid, label=\"1.000\""));
+        assert!(dot.contains("color=red, style=dashed, label=\"2.000\""));
+        assert!(dot.contains("color=blue"));
+    }
+
+    #[test]
+    fn graph_adjacency_matrix_sums_parallel_edges() {
+        let mut graph: FractalGraph<Complex<f32>> = FractalGraph::new();
+        let a = graph.add_node(Complex::new(1.0, 0.0));
+        let b = graph.add_node(Complex::new(0.0, 1.0));
+        let c = graph.add_node(Complex::new(0.0, 0.0));
+        graph.add_edge(a, b, EdgeType::Excitatory, Complex::new(1.0, 0.0)).unwrap();
+        graph.add_edge(a, b, EdgeType::Resonant, Complex::new(0.0, 2.0)).unwrap();
+        graph.add_edge(c, a, EdgeType::Inhibitory, Complex::new(-0.5, 0.0)).unwrap();
+
+        let (ids, matrix) = graph.to_adjacency_matrix();
+        assert_eq!(ids, vec![a, b, c]);
+        let zero = Complex::new(0.0, 0.0);
+        assert_eq!(matrix[0], vec![zero, Complex::new(1.0, 2.0), zero]);
+        assert_eq!(matrix[1], vec![zero, zero, zero]);
+        assert_eq!(matrix[2], vec![Complex::new(-0.5, 0.0), zero, zero]);
+    }
+
+    #[test]
+    fn graph_merge_reassigns_ids_without_collisions() {
+        let mut left: FractalGraph<i32> = FractalGraph::new();
+        let a = left.add_node(1);
+        let b = left.add_node(2);
+        left.add_edge(a, b, EdgeType::Excitatory, Complex::new(1.0, 0.0)).unwrap();
+
+        let mut right: FractalGraph<i32> = FractalGraph::new();
+        let x = right.add_node(10);
+        let y = right.add_node(20);
+        let z = right.add_node(30);
+        right.add_edge(x, y, EdgeType::Resonant, Complex::new(0.5, 0.0)).unwrap();
+        right.add_edge(z, x, EdgeType::Inhibitory, Complex::new(-1.0, 0.0)).unwrap();
+
+        let mapping = left.merge(right);
+        assert_eq!(left.node_count(), 5);
+        assert_eq!(left.edge_count(), 3);
+        assert_eq!(mapping.len(), 3);
+        for new_id in mapping.values() {
+            assert!(*new_id != a && *new_id != b);
+        }
+        assert_eq!(left.get_node(mapping[&y]).unwrap().payload, 20);
+        let (ids, matrix) = left.to_adjacency_matrix();
+        let pos = |id: NodeId| ids.iter().position(|i| *i == id).unwrap();
+        assert_eq!(matrix[pos(mapping[&z])][pos(mapping[&x])], Complex::new(-1.0, 0.0));
+
+        // Nodes added after the merge still get unique IDs.
+        let fresh = left.add_node(99);
+        assert!(!mapping.values().any(|id| *id == fresh));
+    }
+
+    #[test]
+    fn graph_degrees_on_star_graph() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let hub = graph.add_node(0);
+        let leaves: Vec<NodeId> = (1..=4).map(|i| graph.add_node(i)).collect();
+        for leaf in &leaves {
+            graph.add_edge(hub, *leaf, EdgeType::Excitatory, Complex::new(1.0, 0.0)).unwrap();
+        }
+
+        assert_eq!(graph.out_degree(hub), 4);
+        assert_eq!(graph.in_degree(hub), 0);
+        assert_eq!(graph.out_degree(leaves[0]), 0);
+        assert_eq!(graph.in_degree(leaves[0]), 1);
+
+        let distribution = graph.degree_distribution();
+        assert_eq!(distribution.get(&4), Some(&1));
+        assert_eq!(distribution.get(&1), Some(&4));
+        assert!(graph.is_connected());
+    }
+
+    #[test]
+    fn graph_with_isolated_component_is_not_connected() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let a = graph.add_node(1);
+        let b = graph.add_node(2);
+        let c = graph.add_node(3);
+        let d = graph.add_node(4);
+        graph.add_edge(a, b, EdgeType::Resonant, Complex::new(1.0, 0.0)).unwrap();
+        graph.add_edge(d, c, EdgeType::Resonant, Complex::new(1.0, 0.0)).unwrap();
+        assert!(!graph.is_connected());
+
+        // Direction is ignored: b -> c joins the two components.
+        graph.add_edge(b, c, EdgeType::Resonant, Complex::new(1.0, 0.0)).unwrap();
+        assert!(graph.is_connected());
+    }
+
+    #[test]
+    fn graph_pagerank_on_directed_triangle() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let a = graph.add_node(1);
+        let b = graph.add_node(2);
+        let c = graph.add_node(3);
+        graph.add_edge(a, b, EdgeType::Excitatory, Complex::new(2.0, 0.0)).unwrap();
+        graph.add_edge(b, c, EdgeType::Excitatory, Complex::new(0.0, 1.0)).unwrap();
+        graph.add_edge(c, a, EdgeType::Excitatory, Complex::new(-3.0, 0.0)).unwrap();
+
+        // A directed cycle is symmetric, so every node ranks equally.
+        let ranks = graph.pagerank(0.85, 50);
+        for id in [a, b, c] {
+            assert!((ranks[&id] - 1.0 / 3.0).abs() < 1e-9);
+        }
+
+        // Redirecting c to a dangling sink concentrates rank downstream.
+        let d = graph.add_node(4);
+        graph.add_edge(c, d, EdgeType::Excitatory, Complex::new(3.0, 0.0)).unwrap();
+        let ranks = graph.pagerank(0.85, 100);
+        assert!((ranks.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(ranks[&c] > ranks[&b] && ranks[&b] > ranks[&a]);
+    }
+
+    /// A graph of `count` zero-valued nodes for an `EntangledSystem`, with their ids.
+    fn particle_graph(count: usize) -> (FractalGraph<Complex<f32>>, Vec<NodeId>) {
+        let mut graph = FractalGraph::new();
+        let nodes = (0..count).map(|_| graph.add_node(Complex::new(0.0, 0.0))).collect();
+        (graph, nodes)
+    }
+
+    /// A stationary particle whose pattern and state are its edges from `core` to `dests`.
+    fn particle(core: NodeId, dests: Vec<NodeId>) -> ParticleResonance {
+        ParticleResonance {
+            pattern_nodes: dests.clone(),
+            core_node: core,
+            state_edges: dests,
+            phase: 0.0,
+            base_frequency: 0.0,
+        }
+    }
+
+    #[test]
+    fn entangled_system_run_sequence_records_each_pulse() {
+        let (mut graph, nodes) = particle_graph(4);
+        let (a, b, c, d) = (nodes[0], nodes[1], nodes[2], nodes[3]);
+        graph.add_edge(a, b, EdgeType::Resonant, Complex::new(1.0, 0.0)).unwrap();
+        graph.add_edge(c, d, EdgeType::Resonant, Complex::new(1.0, 0.0)).unwrap();
+        let mut system = EntangledSystem::new(graph, particle(a, vec![b]), particle(c, vec![d]));
+
+        let pulse = EntropyPulse { frequency: PI as f64 / 2.0, amplitude: 2.0, waveform: "sine".into(), phase: 0.0 };
+        let feedback = system.run_sequence(&vec![pulse; 3]);
+        assert_eq!(feedback.len(), 3);
+
+        // The pulse matches the initial state exactly; the opposing rotations then cancel.
+        assert!(feedback[0].correlation_strength < 1e-6);
+        assert!((feedback[1].correlation_strength - 2.0).abs() < 1e-5);
+        // Three quarter turns leave the first particle at -i.
+        let rotated = system.particle_amplitude(&system.particles.0);
+        assert!((rotated - Complex::new(0.0, -1.0)).norm() < 1e-5);
+    }
+
+    #[test]
+    fn particle_oscillation_rotates_state_edges() {
+        let (mut graph, nodes) = particle_graph(3);
+        let (core, state, other) = (nodes[0], nodes[1], nodes[2]);
+        graph.add_edge(core, state, EdgeType::Resonant, Complex::new(2.0, 0.0)).unwrap();
+        graph.add_edge(core, other, EdgeType::Resonant, Complex::new(1.0, 0.0)).unwrap();
+
+        let mut oscillator = ParticleResonance { base_frequency: 0.25, ..particle(core, vec![state]) };
+        oscillator.oscillate(&mut graph, 1.0);
+        assert!((oscillator.phase - PI / 2.0).abs() < 1e-6);
+
+        let edges = graph.get_edges_for_node(core).unwrap();
+        let weight_to = |dest| edges.iter().find(|e| e.destination == dest).unwrap().weight;
+        // The state edge keeps its amplitude at the new phase; other edges are untouched.
+        assert!((weight_to(state) - Complex::new(0.0, 2.0)).norm() < 1e-5);
+        assert_eq!(weight_to(other), Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn entanglement_correlation_of_identical_and_orthogonal_particles() {
+        let (mut graph, nodes) = particle_graph(6);
+        let (core_a, core_b) = (nodes[0], nodes[1]);
+        let (a1, a2, b1, b2) = (nodes[2], nodes[3], nodes[4], nodes[5]);
+        graph.add_edge(core_a, a1, EdgeType::Resonant, Complex::new(1.0, 0.0)).unwrap();
+        graph.add_edge(core_a, a2, EdgeType::Resonant, Complex::new(0.0, 1.0)).unwrap();
+        graph.add_edge(core_b, b1, EdgeType::Resonant, Complex::new(1.0, 0.0)).unwrap();
+        graph.add_edge(core_b, b2, EdgeType::Resonant, Complex::new(0.0, 1.0)).unwrap();
+        let mut system = EntangledSystem::new(graph, particle(core_a, vec![a1, a2]), particle(core_b, vec![b1, b2]));
+        assert!((system.entanglement_correlation() - 1.0).abs() < 1e-9);
+
+        // Flipping one of b's weights makes the two states orthogonal.
+        system.graph.get_edges_for_node_mut(core_b).unwrap()[1].weight = Complex::new(0.0, -1.0);
+        assert!(system.entanglement_correlation().abs() < 1e-9);
+
+        system.graph.get_edges_for_node_mut(core_b).unwrap()[0].weight = Complex::new(-1.0, 0.0);
+        assert!((system.entanglement_correlation() + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn entangled_system_relax_scales_weights() {
+        let (mut graph, nodes) = particle_graph(2);
+        let (a, b) = (nodes[0], nodes[1]);
+        graph.add_edge(a, b, EdgeType::Resonant, Complex::new(2.0, -4.0)).unwrap();
+        graph.add_edge(b, a, EdgeType::Inhibitory, Complex::new(0.0, 1.0)).unwrap();
+        let mut system = EntangledSystem::new(graph, particle(a, vec![b]), particle(b, vec![a]));
+
+        system.relax(0.5);
+        assert_eq!(system.particle_amplitude(&system.particles.0), Complex::new(1.0, -2.0));
+        assert_eq!(system.particle_amplitude(&system.particles.1), Complex::new(0.0, 0.5));
+    }
+
+    #[test]
+    fn with_entropy_matches_unbounded_formula_and_leaves_original() {
+        let edge = FractalField::one()[0];
+        let modulated = edge.with_entropy(0.5);
+        assert_eq!(edge, FractalField::one()[0]);
+        assert!((modulated.length - 1.5).abs() < 1e-6);
+        assert!((modulated.data - Complex::new(1.5, 0.5)).norm() < 1e-5);
+
+        let mut in_place = edge;
+        in_place.apply_entropy(0.5);
+        assert_eq!(in_place, modulated);
+    }
+
+    #[test]
+    fn with_entropy_saturates_at_ceilings() {
+        let bounds = EntropyBounds { max_length: 10.0, max_amplitude: 5.0 };
+        let mut edge = FractalField::one()[0];
+        for _ in 0..1000 {
+            edge = edge.with_entropy_bounded(100.0, bounds);
+        }
+        assert_eq!(edge.length, 10.0);
+        assert!((edge.data.norm() - 5.0).abs() < 1e-4);
+
+        // The default ceilings keep long runs finite.
+        let mut edge = FractalField::one()[0];
+        for _ in 0..1000 {
+            edge.apply_entropy(1e3);
+        }
+        assert!(edge.length.is_finite() && edge.data.norm().is_finite());
+        assert!(edge.data.norm() <= EntropyBounds::default().max_amplitude * 1.0001);
+    }
+
+    #[test]
+    fn with_entropy_clamps_only_new_growth() {
+        // A length already past the ceiling is kept, but does not grow.
+        let mut edge = FractalField::one()[0].scaled(5e6);
+        edge.apply_entropy(0.0);
+        assert_eq!(edge.length, 5e6);
+        edge.apply_entropy(1.0);
+        assert_eq!(edge.length, 5e6);
+        assert_eq!(edge.with_entropy(-0.5).length, 2.5e6);
+
+        // Below -1 the length flips sign each step, but its magnitude stays bounded.
+        let mut edge = FractalField::one()[0];
+        for _ in 0..201 {
+            edge.apply_entropy(-3.0);
+        }
+        assert_eq!(edge.length.abs(), EntropyBounds::default().max_length);
+        assert!(edge.data.norm() <= EntropyBounds::default().max_amplitude * 1.0001);
+    }
+
+    #[test]
+    fn field_energy_and_power() {
+        // |1|² + |i|² + |1 + i|² = 1 + 1 + 2
+        let field = canonical_test_fractal();
+        assert!((field.energy() - 4.0).abs() < 1e-6);
+        assert!((field.power() - 4.0 / 3.0).abs() < 1e-6);
+
+        assert!(((field * Complex::new(2.0, 0.0)).energy() - 16.0).abs() < 1e-5);
+        assert_eq!(FractalField::zero().energy(), 0.0);
+        assert_eq!(FractalField::zero().power(), 0.0);
+    }
+
+    #[test]
+    fn field_prune_removes_weak_edges() {
+        let field = field_of_phasors(&[(1.0, 0.0), (0.01, 1.0), (0.5, 2.0), (0.0, 0.0)]);
+        let pruned = field.prune(0.1);
+        assert_eq!(pruned.len(), 2);
+        assert_eq!(pruned[0], field[0]);
+        assert_eq!(pruned[1], field[2]);
+
+        let mut in_place = field.clone();
+        in_place.prune_in_place(0.1);
+        assert_eq!(in_place, pruned);
+        assert_eq!(field.prune(0.0), field);
+    }
+
+    #[test]
+    fn simplicity_critic_peaks_at_target_size() {
+        let critic = SimplicityCritic { target_edges: 3 };
+        assert_eq!(critic.score(&canonical_test_fractal()), 0.0);
+        assert_eq!(critic.score(&FractalField::one()), -2.0);
+        let larger = canonical_test_fractal().concat(&canonical_test_fractal());
+        assert_eq!(critic.score(&larger), -3.0);
+    }
+
+    #[test]
+    fn random_field_generator_yields_fields_lazily() {
+        let generator = RandomFieldGenerator { count: 4, mutation_strength: 0.1 };
+        let fields: Vec<FractalField> = generator.generate_iter().take(25).collect();
+        assert_eq!(fields.len(), 25);
+        assert!(fields.iter().all(|f| f.len() == 5));
+        assert_eq!(generator.generate().len(), 4);
+    }
+
+    #[test]
+    fn loop_builder_configures_and_stops_early() {
+        let mut critics = CriticSuite::new();
+        critics.add_critic(UnitAmplitudeCritic, 1.0);
+        let loop_engine = GeneratorCriticLoopBuilder::new()
+            .generator(StayOrLeapGenerator)
+            .critics(critics)
+            .iterations(50)
+            .patience(3)
+            .build()
+            .unwrap();
+        assert_eq!(loop_engine.iterations, 50);
+        assert_eq!(loop_engine.diversity_weight, 0.0);
+        assert_eq!(loop_engine.run(), Some(FractalField::one()));
+
+        // The seed is never improved on, so the loop gives up three iterations after it.
+        let report = loop_engine.run_with_report().unwrap();
+        assert_eq!(report.history.len(), 4);
+
+        // Without a generator there is nothing to build.
+        assert!(GeneratorCriticLoopBuilder::<StayOrLeapGenerator>::new().build().is_none());
+    }
+
+    #[test]
+    fn loop_resumed_from_good_seed_is_never_worse() {
+        struct TargetNineCritic;
+        impl Critic for TargetNineCritic {
+            fn score(&self, field: &FractalField) -> f32 {
+                -(field.signature().total_amplitude - 9.0).abs()
+            }
+        }
+        let critics = || {
+            let mut suite = CriticSuite::new();
+            suite.add_critic(TargetNineCritic, 1.0);
+            suite
+        };
+        let loop_engine = GeneratorCriticLoopBuilder::new()
+            .generator(StayOrLeapGenerator)
+            .critics(critics())
+            .iterations(2)
+            .build()
+            .unwrap();
+
+        // Fresh: 1 -> 3. Seeded at the optimum, leaping to 27 is rejected.
+        let fresh = loop_engine.run().unwrap();
+        let seed = FractalField::one() * Complex::new(9.0, 0.0);
+        let resumed = loop_engine.run_from(seed.clone()).unwrap();
+        let score = |f: &FractalField| loop_engine.critic_suite.score(f);
+        assert!(score(&resumed) >= score(&fresh));
+        assert_eq!(resumed, seed);
+
+        // Niching favours the distant leap, but it still cannot displace the better seed.
+        let niched = GeneratorCriticLoopBuilder::new()
+            .generator(StayOrLeapGenerator)
+            .critics(critics())
+            .iterations(2)
+            .diversity_weight(2.0)
+            .build()
+            .unwrap();
+        assert_eq!(niched.run_from(seed.clone()).unwrap(), seed);
+
+        // Resuming a report picks up where it stopped and keeps its history.
+        let report = loop_engine.run_with_report().unwrap();
+        assert!((report.best_score + 6.0).abs() < 1e-5);
+        let resumed = loop_engine.resume_with_report(report).unwrap();
+        assert_eq!(resumed.history.len(), 4);
+        assert!(resumed.best_score.abs() < 1e-5);
+    }
+
+    #[test]
+    fn field_subtraction_is_addition_of_negation() {
+        let a = canonical_test_fractal();
+        let b = field_of_phasors(&[(2.0, 0.5), (0.5, -1.0), (1.5, 2.0)]);
+
+        let zero = a.clone() - a.clone();
+        assert!(zero.is_zero());
+        assert_eq!(zero.len(), a.len());
+        assert_eq!(a.clone() - b.clone(), a.clone() + (-b));
+    }
+
+    #[test]
+    fn empty_field_is_the_additive_identity() {
+        let a = canonical_test_fractal();
+        let zero = <FractalField as VectorSpace>::zero();
+        assert_eq!(a.clone() + zero.clone(), a);
+        assert_eq!(zero.clone() + a.clone(), a);
+        assert_eq!(a.clone() - zero.clone(), a);
+        assert_eq!(zero - a.clone(), -a);
+        assert!(super::test_add_identity());
+    }
+
+    #[test]
+    fn fractal_field_satisfies_vector_space_axioms() {
+        crate::test_vector_space_axioms!(canonical_test_fractal(), Complex::new(2.0, 3.0));
+        crate::test_vector_space_axioms!(FractalField::one(), Complex::new(-0.5, 1.0));
+    }
+
+    #[test]
+    fn field_real_scaling_matches_complex_scaling() {
+        let field = canonical_test_fractal();
+        assert_eq!(field.clone() * 2.0, field.clone() * Complex::new(2.0, 0.0));
+        assert_eq!(field.clone() * 1.0, field);
+    }
+
+    #[test]
+    fn field_scalar_multiplication_commutes() {
+        let field = canonical_test_fractal();
+        let c = Complex::new(0.5, -2.0);
+        assert_eq!(c * field.clone(), field.clone() * c);
+        assert_eq!(3.0 * field.clone(), field * 3.0);
+    }
+
+    #[test]
+    fn field_approx_eq_tolerates_float_error() {
+        let field = canonical_test_fractal();
+        let mut perturbed = field.clone();
+        perturbed.edges[0].data += Complex::new(1e-6, 0.0);
+        perturbed.edges[1].origin.y += 1e-6;
+        perturbed.edges[2].length -= 1e-6;
+
+        assert_ne!(perturbed, field);
+        assert!(perturbed.approx_eq(&field, 1e-4));
+        assert!(!perturbed.approx_eq(&field, 1e-8));
+        assert!(!field.approx_eq(&FractalField::one(), 1.0));
+
+        // Float arithmetic that is exact in theory but not in practice.
+        let roundtrip = (field.clone() * Complex::new(0.1, 0.3)) * (Complex::new(1.0, 0.0) / Complex::new(0.1, 0.3));
+        assert!(roundtrip.approx_eq(&field, 1e-5));
+    }
+
+    #[test]
+    fn vec3_approx_eq_at_epsilon_boundary() {
+        let a = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        let b = Vec3 { x: 1.5, y: 2.0, z: 2.75 };
+        assert!(a.approx_eq(&b, 0.5));
+        assert!(!a.approx_eq(&b, 0.49));
+        assert!(a.approx_eq(&a, 0.0));
+    }
+
+    #[test]
+    fn graph_edge_approx_eq_compares_data_within_epsilon() {
+        let edge = FractalField::one()[0];
+        let nudged = GraphEdge { data: edge.data + Complex::new(0.0, 0.25), ..edge };
+        assert!(edge.approx_eq(&nudged, 0.25));
+        assert!(!edge.approx_eq(&nudged, 0.24));
+
+        // Geometry must match exactly.
+        let moved = GraphEdge { length: edge.length + 1e-6, ..edge };
+        assert!(!edge.approx_eq(&moved, 1.0));
+    }
+
+    #[test]
+    fn field_centroid_and_bounding_box() {
+        // Unit edges from the origin along X, Y and Z.
+        let field = canonical_test_fractal();
+        let third = 1.0 / 3.0;
+        assert!(field.centroid().approx_eq(&Vec3 { x: third, y: third, z: third }, 1e-6));
+        assert_eq!(field.bounding_box(), (Vec3::ZERO, Vec3 { x: 1.0, y: 1.0, z: 1.0 }));
+
+        let empty = FractalField::zero();
+        assert_eq!(empty.centroid(), Vec3::ZERO);
+        let (min, max) = empty.bounding_box();
+        assert!(min.x > max.x && min.y > max.y && min.z > max.z);
+    }
+
+    #[test]
+    fn field_affine_transforms_move_geometry_only() {
+        let field = canonical_test_fractal();
+        let data: Vec<_> = field.iter().map(|e| e.data).collect();
+        let same_data = |f: &FractalField| f.iter().map(|e| e.data).eq(data.iter().copied());
+
+        let offset = Vec3 { x: 1.0, y: -2.0, z: 0.5 };
+        let moved = field.translate(offset);
+        assert!(moved.iter().all(|e| e.origin == offset));
+        assert_eq!(moved[1].endpoint(), offset + Vec3::Y);
+        assert!(same_data(&moved));
+
+        let scaled = moved.scale(2.0);
+        assert!(scaled.iter().all(|e| e.origin == offset * 2.0 && e.length == 2.0));
+        assert_eq!(scaled[0].direction, Vec3::X);
+        assert!(same_data(&scaled));
+
+        let rotated = moved.rotate_z(PI / 2.0);
+        assert!(rotated[0].direction.approx_eq(&Vec3::Y, 1e-6));
+        assert!(rotated[1].direction.approx_eq(&-Vec3::X, 1e-6));
+        assert!(rotated[2].direction.approx_eq(&Vec3::Z, 1e-6));
+        assert!(rotated[0].origin.approx_eq(&Vec3 { x: 2.0, y: 1.0, z: 0.5 }, 1e-6));
+        assert!(same_data(&rotated));
+    }
+
+    #[test]
+    fn vec3_rotate_around_axis() {
+        assert!(Vec3::X.rotate_around(Vec3::Z, PI / 2.0).approx_eq(&Vec3::Y, 1e-6));
+        // The axis need not be normalized.
+        assert!(Vec3::Y.rotate_around(Vec3::X * 5.0, PI / 2.0).approx_eq(&Vec3::Z, 1e-6));
+
+        let v = Vec3 { x: 2.0, y: 4.0, z: -6.0 };
+        assert!(v.rotate_around(v * 0.5, 1.234).approx_eq(&v, 1e-5));
+        assert_eq!(v.rotate_around(Vec3::ZERO, 1.0), v);
+
+        // A third of a turn about the diagonal cycles the axes.
+        let diagonal = Vec3 { x: 1.0, y: 1.0, z: 1.0 };
+        assert!(Vec3::X.rotate_around(diagonal, 2.0 * PI / 3.0).approx_eq(&Vec3::Y, 1e-6));
+    }
+
+    #[test]
+    fn vec3_angle_between_and_projection() {
+        assert!((Vec3::X.angle_between(Vec3::Y) - PI / 2.0).abs() < 1e-6);
+        assert_eq!((Vec3::X * 3.0).angle_between(Vec3::X), 0.0);
+        assert!((Vec3::X.angle_between(-Vec3::X) - PI).abs() < 1e-6);
+        let v = Vec3 { x: 0.1, y: 0.7, z: -0.3 };
+        assert!(!v.angle_between(v * 3.0).is_nan());
+        assert_eq!(Vec3::ZERO.angle_between(Vec3::X), 0.0);
+
+        let a = Vec3 { x: 3.0, y: 4.0, z: 0.0 };
+        assert_eq!(a.project_onto(Vec3::X * 2.0), Vec3 { x: 3.0, y: 0.0, z: 0.0 });
+        assert_eq!(Vec3::Z.project_onto(a), Vec3::ZERO);
+        assert_eq!(a.project_onto(Vec3::ZERO), Vec3::ZERO);
+    }
+
+    #[test]
+    fn graph_iterators_match_counts() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let a = graph.add_node(1);
+        let b = graph.add_node(2);
+        let c = graph.add_node(3);
+        graph.add_edge(a, b, EdgeType::Excitatory, Complex::new(1.0, 0.0)).unwrap();
+        graph.add_edge(a, c, EdgeType::Resonant, Complex::new(1.0, 0.0)).unwrap();
+        graph.add_edge(c, b, EdgeType::Inhibitory, Complex::new(1.0, 0.0)).unwrap();
+
+        assert_eq!(graph.nodes().count(), graph.node_count());
+        assert_eq!(graph.node_ids().count(), graph.node_count());
+        assert_eq!(graph.edges().count(), graph.edge_count());
+        assert_eq!(graph.nodes().map(|n| n.payload).sum::<i32>(), 6);
+        assert_eq!(graph.edges().filter(|(source, _)| *source == a).count(), 2);
+        assert!(graph.edges().any(|(source, e)| source == c && e.destination == b));
+    }
+
+    #[test]
+    fn graph_find_cycles_reports_each_cycle_once() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let a = graph.add_node(1);
+        let b = graph.add_node(2);
+        let c = graph.add_node(3);
+        let d = graph.add_node(4);
+        let w = Complex::new(1.0, 0.0);
+        graph.add_edge(a, b, EdgeType::Excitatory, w).unwrap();
+        graph.add_edge(b, c, EdgeType::Excitatory, w).unwrap();
+        graph.add_edge(b, c, EdgeType::Resonant, w).unwrap();
+        graph.add_edge(c, a, EdgeType::Excitatory, w).unwrap();
+        graph.add_edge(c, d, EdgeType::Excitatory, w).unwrap();
+        assert_eq!(graph.find_cycles(), vec![vec![a, b, c]]);
+
+        graph.remove_node(a).unwrap();
+        assert!(graph.is_acyclic());
+        assert!(graph.find_cycles().is_empty());
+    }
+
+    #[test]
+    fn graph_strongly_connected_components() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let w = Complex::new(1.0, 0.0);
+        let nodes: Vec<NodeId> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(nodes[0], nodes[1], EdgeType::Excitatory, w).unwrap();
+        graph.add_edge(nodes[1], nodes[2], EdgeType::Excitatory, w).unwrap();
+        graph.add_edge(nodes[0], nodes[3], EdgeType::Excitatory, w).unwrap();
+
+        // A DAG splits into singletons.
+        let components = graph.strongly_connected_components();
+        assert_eq!(components.len(), 4);
+        assert!(components.iter().all(|c| c.len() == 1));
+
+        // Closing 0 -> 1 -> 2 -> 0 merges those three nodes.
+        graph.add_edge(nodes[2], nodes[0], EdgeType::Resonant, w).unwrap();
+        let mut components = graph.strongly_connected_components();
+        assert_eq!(components.len(), 2);
+        components.iter_mut().for_each(|c| c.sort_by_key(|id| id.0));
+        assert!(components.contains(&vec![nodes[0], nodes[1], nodes[2]]));
+        assert!(components.contains(&vec![nodes[3]]));
+    }
+
+    #[test]
+    fn graph_minimum_spanning_forest() {
+        let mut graph: FractalGraph<i32> = FractalGraph::new();
+        let nodes: Vec<NodeId> = (0..6).map(|i| graph.add_node(i)).collect();
+        let mut connect = |a: usize, b: usize, cost: f32| {
+            // Direction and phase are ignored; only the magnitude counts.
+            graph.add_edge(nodes[a], nodes[b], EdgeType::Resonant, Complex::from_polar(cost, 1.0)).unwrap();
+        };
+        connect(0, 1, 4.0);
+        connect(1, 2, 1.0);
+        connect(2, 0, 2.0);
+        connect(3, 2, 5.0);
+        connect(1, 3, 3.0);
+        connect(4, 5, 7.0);
+
+        let tree = graph.minimum_spanning_tree();
+        // Nodes 0-3 form one tree and 4-5 another.
+        assert_eq!(tree.len(), 4);
+        let costs: Vec<f32> = tree.iter().map(|(_, _, c)| *c).collect();
+        for (cost, expected) in costs.iter().zip([1.0, 2.0, 3.0, 7.0]) {
+            assert!((cost - expected).abs() < 1e-5);
+        }
+        assert_eq!((tree[2].0, tree[2].1), (nodes[1], nodes[3]));
+    }
+
+    #[test]
+    fn quantized_key_buckets_close_signatures() {
+        use std::collections::HashMap;
+
+        let a = signature(0.50, 2.00, 5, 3);
+        let near = signature(0.52, 1.97, 5, 3);
+        let far = signature(1.50, 2.00, 5, 3);
+        let deeper = signature(0.50, 2.00, 5, 4);
+
+        let key = a.quantized_key(0.1);
+        assert_eq!(key, (10, 5, 20, 5, 0, 3));
+        assert_eq!(near.quantized_key(0.1), key);
+        assert_ne!(far.quantized_key(0.1), key);
+        assert_ne!(deeper.quantized_key(0.1), key);
+
+        let mut buckets: HashMap<_, usize> = HashMap::new();
+        for s in [&a, &near, &far, &deeper] {
+            *buckets.entry(s.quantized_key(0.1)).or_default() += 1;
+        }
+        assert_eq!(buckets.len(), 3);
+    }
+
+    #[test]
+    fn novelty_search_grows_archive_with_distinct_fields() {
+        let mut search = NoveltySearch::new(StayOrLeapGenerator, 2, 4);
+        let selected = search.run();
+
+        assert_eq!(selected.len(), 4);
+        assert_eq!(search.archive.len(), 4);
+        // Staying put is never novel, so each generation leaps: amplitudes 1, 3, 9, 27.
+        for (i, field) in selected.iter().enumerate() {
+            assert!((field.signature().total_amplitude - 3f32.powi(i as i32)).abs() < 1e-3);
+        }
+        for (i, a) in selected.iter().enumerate() {
+            assert!(selected[i + 1..].iter().all(|b| a != b));
+        }
+
+        // Running again continues from the persisted archive.
+        search.run();
+        assert_eq!(search.archive.len(), 8);
+    }
+
+    /// Scores a field by its total amplitude.
+    struct AmplitudeCritic;
+
+    impl Critic for AmplitudeCritic {
+        fn score(&self, field: &FractalField) -> f32 {
+            field.signature().total_amplitude
+        }
+    }
+
+    #[test]
+    fn critic_suite_pareto_front_keeps_trade_offs() {
+        let mut suite = CriticSuite::new();
+        suite.add_critic(AmplitudeCritic, 1.0);
+        suite.add_critic(SimplicityCritic { target_edges: 1 }, 0.001);
+
+        // (amplitude, simplicity): a = (1, 0), b = (3.41, -2), c = (2, 0), d = (0.34, -2)
+        let a = FractalField::one();
+        let b = canonical_test_fractal();
+        let c = FractalField::one() * 2.0;
+        let d = canonical_test_fractal() * 0.1;
+        let fields = [a, b.clone(), c.clone(), d];
+
+        // c dominates a, and both b and c dominate d.
+        assert_eq!(suite.pareto_front(&fields), vec![&b, &c]);
+        assert!(suite.pareto_front(&[]).is_empty());
+    }
+
+    #[test]
+    fn benchmark_loop_counts_work() {
+        let mut critics = CriticSuite::new();
+        critics.add_critic(SymmetryCritic, 1.0);
+        critics.add_critic(EntropyCritic, 1.0);
+        let loop_engine = GeneratorCriticLoopBuilder::new()
+            .generator(RandomFieldGenerator { count: 8, mutation_strength: 0.2 })
+            .critics(critics)
+            .iterations(5)
+            .build()
+            .unwrap();
+
+        let stats = benchmark_loop(&loop_engine, 2);
+        assert_eq!(stats.candidates_generated, 40);
+        // Every candidate is scored once, and from the second generation on the selected
+        // candidate and the incumbent are scored again to compare them.
+        assert_eq!(stats.fields_scored, 40 + 4 * 2);
+        assert!(stats.candidates_per_second() >= 0.0);
+
+        // The run stops early once its patience is exhausted.
+        let mut critics = CriticSuite::new();
+        critics.add_critic(UnitAmplitudeCritic, 1.0);
+        let patient = GeneratorCriticLoopBuilder::new()
+            .generator(StayOrLeapGenerator)
+            .critics(critics)
+            .iterations(50)
+            .patience(3)
+            .build()
+            .unwrap();
+        let stats = benchmark_loop(&patient, 0);
+        assert_eq!(stats.candidates_generated, 1 + 3 * 2);
+    }
+
+    #[test]
+    fn cached_signature_scores_match_direct_scores() {
+        let field = canonical_test_fractal() * Complex::new(0.3, 1.1);
+        let signature = field.signature();
+        assert_eq!(SymmetryCritic.score_with_signature(&field, &signature), SymmetryCritic.score(&field));
+        assert_eq!(EntropyCritic.score_with_signature(&field, &signature), EntropyCritic.score(&field));
+        // Critics without an override fall back to `score`.
+        assert_eq!(CoherenceCritic.score_with_signature(&field, &signature), CoherenceCritic.score(&field));
+
+        let mut suite = CriticSuite::new();
+        suite.add_critic(SymmetryCritic, 0.7);
+        suite.add_critic(EntropyCritic, 0.3);
+        suite.add_critic(CoherenceCritic, 1.5);
+        let direct = SymmetryCritic.score(&field) * 0.7 + EntropyCritic.score(&field) * 0.3 + CoherenceCritic.score(&field) * 1.5;
+        assert!((suite.score(&field) - direct).abs() < 1e-6);
+    }
+
+    #[test]
+    fn field_content_hash_tolerates_sub_grid_noise() {
+        let field = canonical_test_fractal();
+        assert_eq!(field.content_hash(), canonical_test_fractal().content_hash());
+
+        let mut noisy = field.clone();
+        noisy.edges[0].data += Complex::new(1e-7, 0.0);
+        assert_eq!(noisy.content_hash(), field.content_hash());
+
+        let mut perturbed = field.clone();
+        perturbed.edges[0].data += Complex::new(1e-2, 0.0);
+        assert_ne!(perturbed.content_hash(), field.content_hash());
+
+        let mut moved = field.clone();
+        moved.edges[1].length = 2.0;
+        assert_ne!(moved.content_hash(), field.content_hash());
+    }
+
+    #[test]
+    fn score_stats_matches_batch_statistics() {
+        let scores = [3.5f32, -1.0, 2.25, 8.0, 0.0, 4.75];
+        let mut stats = ScoreStats::new();
+        for &s in &scores {
+            stats.push(s);
+        }
+
+        let n = scores.len() as f32;
+        let mean = scores.iter().sum::<f32>() / n;
+        let variance = scores.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / n;
+
+        let (count, m, var, min, max) = stats.summary();
+        assert_eq!(count, 6.0);
+        assert!((m - mean).abs() < 1e-5);
+        assert!((var - variance).abs() < 1e-4);
+        assert_eq!(min, -1.0);
+        assert_eq!(max, 8.0);
+
+        let empty = ScoreStats::default().summary();
+        assert_eq!((empty.0, empty.1, empty.2), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn run_with_report_populates_score_stats() {
+        let mut critics = CriticSuite::new();
+        critics.add_critic(UnitAmplitudeCritic, 1.0);
+        let loop_engine = GeneratorCriticLoopBuilder::new()
+            .generator(StayOrLeapGenerator)
+            .critics(critics)
+            .iterations(5)
+            .build()
+            .unwrap();
+        let report = loop_engine.run_with_report().unwrap();
+        assert_eq!(report.score_stats.count, report.history.len());
+        assert_eq!(report.score_stats.max, report.best_score);
+    }
+
+    #[test]
+    fn tournament_select_picks_best_of_sampled_subset() {
+        let fields: Vec<FractalField> = (1..=8).map(|a| field_with_entropy(a as f32, 1.0)).collect();
+        let mut suite = CriticSuite::new();
+        suite.add_critic(AmplitudeCritic, 1.0);
+
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let winner = suite.tournament_select(&fields, 3, &mut rng).unwrap();
+
+            // Replay the same draws to recover the sampled subset.
+            let mut replay = StdRng::seed_from_u64(seed);
+            let best_sampled = (0..3)
+                .map(|_| replay.random_range(0..fields.len()))
+                .max()
+                .unwrap();
+            assert_eq!(winner, &fields[best_sampled]);
+        }
+
+        assert!(suite.tournament_select(&[], 3, &mut StdRng::seed_from_u64(0)).is_none());
+    }
+
+    #[test]
+    fn roulette_select_is_proportional_to_shifted_scores() {
+        // Amplitudes 1, 2, 3 shift to weights 0, 1, 2.
+        let fields: Vec<FractalField> = (1..=3).map(|a| field_with_entropy(a as f32, 1.0)).collect();
+        let mut suite = CriticSuite::new();
+        suite.add_critic(AmplitudeCritic, 1.0);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts = [0usize; 3];
+        let samples = 3000;
+        for _ in 0..samples {
+            let chosen = suite.roulette_select(&fields, &mut rng).unwrap();
+            counts[fields.iter().position(|f| f == chosen).unwrap()] += 1;
+        }
+        assert_eq!(counts[0], 0);
+        let share = counts[2] as f32 / samples as f32;
+        assert!((share - 2.0 / 3.0).abs() < 0.05, "share of best field was {share}");
+
+        // Equal scores fall back to uniform selection.
+        let equal: Vec<FractalField> =
+            (1..=3).map(|x| FractalField::one().translate(Vec3 { x: x as f32, y: 0.0, z: 0.0 })).collect();
+        let mut equal_counts = [0usize; 3];
+        for _ in 0..samples {
+            let chosen = suite.roulette_select(&equal, &mut rng).unwrap();
+            equal_counts[equal.iter().position(|f| f == chosen).unwrap()] += 1;
+        }
+        assert!(equal_counts.iter().all(|&c| c > samples / 4));
+        assert!(suite.roulette_select(&[], &mut rng).is_none());
+    }
+
+    #[test]
+    fn lerp_interpolates_between_fields() {
+        let a = canonical_test_fractal();
+        let b = a.translate(Vec3 { x: 2.0, y: 0.0, z: 0.0 }).scale(3.0) * Complex::new(0.0, 2.0);
+
+        assert!(a.lerp(&b, 0.0).approx_eq(&a, 1e-6));
+        assert!(a.lerp(&b, 1.0).approx_eq(&b, 1e-6));
+
+        let mid = a.lerp(&b, 0.5);
+        for ((m, ea), eb) in mid.iter().zip(a.iter()).zip(b.iter()) {
+            assert!((m.length - (ea.length + eb.length) / 2.0).abs() < 1e-5);
+            assert!(m.origin.approx_eq(&((ea.origin + eb.origin) * 0.5), 1e-5));
+            assert!((m.data - (ea.data + eb.data) * 0.5).norm() < 1e-5);
+        }
+
+        // Pairs are zipped, so the result is as long as the shorter field.
+        assert_eq!(a.lerp(&FractalField::one(), 0.5).len(), 1);
+    }
+
+    #[test]
+    fn slerp_keeps_directions_unit_length() {
+        let pairs = [
+            (Vec3::X, Vec3::Y),
+            (Vec3::X, Vec3 { x: -1.0, y: 0.001, z: 0.0 }),
+            (Vec3::Z, -Vec3::Z),
+            (Vec3::Y, Vec3::Y),
+            (Vec3 { x: 3.0, y: 4.0, z: 0.0 }, Vec3 { x: 0.0, y: 0.0, z: 2.0 }),
+        ];
+        for (a, b) in pairs {
+            assert!(a.slerp(b, 0.0).approx_eq(&a.normalize(), 1e-5));
+            assert!(a.slerp(b, 1.0).approx_eq(&b.normalize(), 1e-5));
+            for step in 1..10 {
+                let t = step as f32 / 10.0;
+                assert!((a.slerp(b, t).norm() - 1.0).abs() < 1e-5, "{a:?} -> {b:?} at {t}");
+            }
+        }
+
+        // Halfway between X and Y is the diagonal, where a linear blend would have length 0.71.
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        assert!(Vec3::X.slerp(Vec3::Y, 0.5).approx_eq(&Vec3 { x: half, y: half, z: 0.0 }, 1e-6));
+
+        // A zero vector has no direction, so slerp degrades to the linear blend.
+        assert_eq!(Vec3::ZERO.slerp(Vec3::X, 0.5), Vec3::X * 0.5);
+        assert_eq!(Vec3::Y.slerp(Vec3::ZERO, 0.25), Vec3::Y * 0.75);
+
+        let a = canonical_test_fractal();
+        let b = a.rotate_z(PI / 2.0);
+        assert!(a.lerp(&b, 0.5).iter().all(|e| (e.direction.norm() - 1.0).abs() < 1e-5));
+
+        let mut degenerate = a.clone();
+        degenerate.edges[0].direction = Vec3::ZERO;
+        let mid = degenerate.lerp(&a, 0.5);
+        assert_eq!(mid.edges[0].direction, a.edges[0].direction * 0.5);
+        assert!(mid.iter().all(|e| !e.direction.x.is_nan() && !e.direction.y.is_nan() && !e.direction.z.is_nan()));
+    }
+
+    #[test]
+    fn generator_registry_creates_generators_by_name() {
+        let registry = GeneratorRegistry::with_builtins();
+        assert_eq!(registry.names(), vec!["evolutionary", "random_field"]);
+        assert!(registry.create("missing").is_none());
+
+        let random = registry.create("random_field").unwrap();
+        assert_eq!(random.generate().len(), 10);
+        let evolutionary = registry.create("evolutionary").unwrap();
+        assert_eq!(evolutionary.generate(), vec![FractalField::one()]);
+
+        // Boxed generators can drive the loop directly.
+        let mut critics = CriticSuite::new();
+        critics.add_critic(UnitAmplitudeCritic, 1.0);
+        let loop_engine = GeneratorCriticLoopBuilder::new()
+            .generator(registry.create("evolutionary").unwrap())
+            .critics(critics)
+            .iterations(2)
+            .build()
+            .unwrap();
+        assert!(loop_engine.run().is_some());
+    }
+
+    #[test]
+    fn from_polar_fn_builds_analytic_fields() {
+        let constant = FractalField::from_polar_fn(16, |i| 1.0 + i as f32, |_| 0.7);
+        assert_eq!(constant.len(), 16);
+        assert!((constant.phase_coherence() - 1.0).abs() < 1e-5);
+        assert!((constant[3].data.norm() - 4.0).abs() < 1e-5);
+        assert!(constant.iter().all(|e| (e.direction.norm() - 1.0).abs() < 1e-5));
+
+        // A full turn of phase cancels out completely.
+        let sinusoid = FractalField::from_polar_fn(16, |_| 1.0, |i| std::f32::consts::TAU * i as f32 / 16.0);
+        assert!(sinusoid.phase_coherence() < 1e-5);
+
+        let edges = FractalField::from_fn(3, |i| GraphEdge { depth: i as u32, ..FractalField::one()[0] });
+        assert_eq!(edges.iter().map(|e| e.depth).collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn signature_at_depth_ignores_deeper_edges() {
+        let field = FractalField::from_fn(6, |i| GraphEdge {
+            depth: (i % 3) as u32,
+            data: Complex::from_polar(1.0 + i as f32, 0.5),
+            ..FractalField::one()[0]
+        });
+
+        let shallow = field.signature_at_depth(1);
+        assert_eq!(shallow.edge_count, 4);
+        assert_eq!(shallow.depth_range, (0, 1));
+        // Amplitudes at depths 0 and 1 are 1, 2, 4 and 5.
+        assert!((shallow.total_amplitude - 12.0).abs() < 1e-4);
+
+        assert_eq!(field.signature_at_depth(2), field.signature());
+        assert_eq!(field.signature_at_depth(0).edge_count, 2);
+        assert_eq!(FractalField::zero().signature_at_depth(5), FractalField::zero().signature());
+    }
+
+    #[test]
+    fn by_depth_partitions_edges_by_level() {
+        let field = FractalField::from_fn(7, |i| GraphEdge { depth: [2, 0, 2, 5, 0, 2, 1][i], ..FractalField::one()[0] });
+        let levels = field.by_depth();
+
+        assert_eq!(levels.keys().copied().collect::<Vec<_>>(), vec![0, 1, 2, 5]);
+        assert_eq!(levels.values().map(FractalField::len).sum::<usize>(), field.len());
+        assert_eq!(levels[&2].len(), 3);
+        assert!(levels.iter().all(|(depth, sub)| sub.iter().all(|e| e.depth == *depth)));
+        assert!(FractalField::zero().by_depth().is_empty());
+    }
+
+    #[test]
+    fn score_field_filter_uses_resonance_score() {
+        // A phase of π maximises each edge's phase alignment term, scoring 2 per unit amplitude.
+        let strong = field_with_entropy(3.0, PI);
+        let weak = field_with_entropy(0.1, PI);
+        let filter = ScoreFieldFilter { min_score: 1.0 };
+        assert!(filter.passes(&strong));
+        assert!(!weak.passes(&filter));
+    }
+
+    #[test]
+    fn law_field_filter_checks_dominant_law() {
+        let harmony = FractalField::one();
+        let dissonance = field_with_entropy(1.0, PI);
+        let filter = LawFieldFilter { allowed: vec![ResonanceLaw::Harmony] };
+        assert!(filter.passes(&harmony));
+        assert!(!filter.passes(&dissonance));
+    }
+
+    #[test]
+    fn entropy_range_filter_is_inclusive() {
+        let fields: Vec<FractalField> = [0.0, 1.0, 2.0, 3.0].iter().map(|e| field_with_entropy(*e, 1.0)).collect();
+        // Use the fields' own entropies as bounds, so that both ends are hit exactly.
+        let entropy = |i: usize| fields[i].signature().entropy;
+        let filter = EntropyRangeFilter { min: entropy(1), max: entropy(2) };
+        let kept = FractalField::filter_fields(&fields, &filter);
+        assert_eq!(kept, fields[1..3].to_vec());
+    }
+
+    /// An IFS member with `transform_count` transforms, which is also its resonance score.
+    fn ifs_member(transform_count: u32, operation: Operation) -> CollectionMember {
+        CollectionMember {
+            fractal: FractalType::IFS(IFS { transform_count, ..IFS::default() }),
+            operation,
+        }
+    }
+
+    /// A collection of `Union`ed IFS members with the given transform counts.
+    fn ifs_collection(counts: &[u32]) -> ResonantFractalCollection {
+        let members = counts.iter().map(|&n| ifs_member(n, Operation::Union)).collect();
+        ResonantFractalCollection::new(FractalCollection { members })
+    }
+
+    #[test]
+    fn fractal_predicate_filter_filters_collections() {
+        let collection = ifs_collection(&[1, 2, 3, 4]);
+
+        let filter = FractalPredicateFilter { predicate: Box::new(|f| f.resonance_score() >= 3.0) };
+        let kept = collection.filter(&filter);
+        assert_eq!(kept.members.len(), 2);
+        assert!(kept.members.iter().all(|m| m.fractal.resonance_score() >= 3.0));
+
+        // The semantic-level predicate cannot see the fractal and lets everything through.
+        let semantic = PredicateFilter { predicate: Box::new(|_| false) };
+        assert_eq!(collection.filter(&semantic).members.len(), 4);
+    }
+
+    fn tagged_mandelbrot(tags: &[&str]) -> Mandelbrot {
+        Mandelbrot { tags: TagSet::new(tags.iter().copied()).unwrap(), ..Mandelbrot::default() }
+    }
+
+    #[test]
+    fn any_tag_filter_needs_only_one_tag() {
+        let filter = AnyTagFilter { tags: vec!["spiral".into(), "dense".into()] };
+        assert!(filter.passes(&tagged_mandelbrot(&["dense", "red"])));
+        assert!(filter.passes(&tagged_mandelbrot(&["spiral"])));
+        assert!(!filter.passes(&tagged_mandelbrot(&["sparse", "red"])));
+        assert!(!AnyTagFilter { tags: vec![] }.passes(&tagged_mandelbrot(&["red"])));
+    }
+
+    #[test]
+    fn tag_prefix_filter_matches_tag_starts() {
+        let filter = TagPrefixFilter { prefix: "color:".into() };
+        assert!(filter.passes(&tagged_mandelbrot(&["alpha", "color:red", "zeta"])));
+        assert!(!filter.passes(&tagged_mandelbrot(&["alpha", "colors", "red:color:"])));
+        assert!(!filter.passes(&tagged_mandelbrot(&["zeta"])));
+        assert!(TagPrefixFilter { prefix: String::new() }.passes(&tagged_mandelbrot(&["any"])));
+
+        // The new filters compose with the existing composite filters.
+        let either = CompositeFilter::Any(vec![
+            Box::new(filter),
+            Box::new(AnyTagFilter { tags: vec!["mono".into()] }),
+        ]);
+        assert!(either.passes(&tagged_mandelbrot(&["mono"])));
+        assert!(!either.passes(&tagged_mandelbrot(&["poly"])));
+    }
+
+    #[test]
+    fn description_filter_matches_substrings() {
+        let described = |description: Option<&str>| Mandelbrot {
+            metadata: Metadata { domain: "art".into(), description: description.map(String::from) },
+            ..Mandelbrot::default()
+        };
+        let exact = DescriptionFilter { needle: "Spiral".into(), case_insensitive: false };
+        let loose = DescriptionFilter { needle: "Spiral".into(), case_insensitive: true };
+
+        assert!(exact.passes(&described(Some("A Spiral arm"))));
+        assert!(!exact.passes(&described(Some("a spiral arm"))));
+        assert!(loose.passes(&described(Some("a SPIRAL arm"))));
+        assert!(!loose.passes(&described(Some("a filament"))));
+        assert!(!exact.passes(&described(None)));
+        assert!(!loose.passes(&described(None)));
+    }
+
+    #[test]
+    fn filter_trace_summarizes_selectivity() {
+        let trace = FilterTrace { filter_name: "score".into(), passed: vec![0, 2, 5], failed: vec![1, 3, 4, 6, 7] };
+        assert_eq!(trace.total(), 8);
+        assert!((trace.pass_rate() - 0.375).abs() < 1e-6);
+        assert_eq!(trace.to_string(), "score: 3/8 passed (37.5%)");
+
+        let empty = FilterTrace { filter_name: "none".into(), passed: vec![], failed: vec![] };
+        assert_eq!(empty.pass_rate(), 0.0);
+        assert_eq!(empty.to_string(), "none: 0/0 passed (0.0%)");
+    }
+
+    #[test]
+    fn trace_pipeline_traces_each_stage_on_survivors() {
+        let collection = ifs_collection(&[1, 2, 3, 4, 5, 6]);
+
+        let at_least_three = FractalPredicateFilter { predicate: Box::new(|f| f.resonance_score() >= 3.0) };
+        let even = FractalPredicateFilter { predicate: Box::new(|f| f.resonance_score() % 2.0 == 0.0) };
+        let (result, traces) = collection.trace_pipeline(&[("min", &at_least_three), ("even", &even)]);
+
+        assert_eq!(traces[0].passed, vec![2, 3, 4, 5]);
+        assert_eq!(traces[0].failed, vec![0, 1]);
+        // The second stage only sees the four survivors of the first.
+        assert_eq!(traces[1].total(), 4);
+        assert_eq!(traces[1].passed, vec![3, 5]);
+        assert_eq!(traces[1].to_string(), "even: 2/4 passed (50.0%)");
+        let scores: Vec<f64> = result.members.iter().map(|m| m.fractal.resonance_score()).collect();
+        assert_eq!(scores, vec![4.0, 6.0]);
+    }
+
+    #[test]
+    fn reevaluate_member_matches_full_reevaluation() {
+        let mut incremental = ifs_collection(&[1, 2, 3, 4, 5]);
+
+        incremental.collection.members[2] = ifs_member(40, Operation::Union);
+        let mut full = incremental.clone();
+        incremental.reevaluate_member(2);
+        full.reevaluate();
+
+        assert_eq!(incremental.resonance_scores, full.resonance_scores);
+        assert_eq!(incremental.resonance_laws, full.resonance_laws);
+        assert!((incremental.average_resonance - full.average_resonance).abs() < 1e-9);
+        assert!((full.average_resonance - 10.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn collection_law_counts_and_score_histogram() {
+        let mut collection = ifs_collection(&[1, 2, 3]);
+        // log_4(2) = 0.5, so this Mandelbrot scores 2.0.
+        let mandelbrot = Mandelbrot { center_re: 2.0, zoom: 4.0, ..Mandelbrot::default() };
+        collection.collection.members.push(CollectionMember {
+            fractal: FractalType::Mandelbrot(mandelbrot),
+            operation: Operation::Union,
+        });
+        collection.reevaluate();
+
+        let laws = collection.law_counts();
+        assert_eq!(laws.len(), 2);
+        assert_eq!(laws[&ResonanceLaw::FractalGrowth], 3);
+        assert_eq!(laws[&ResonanceLaw::Echo], 1);
+
+        // Scores 1, 2, 2 and 3 over two bins of width 1.
+        assert_eq!(collection.score_histogram(2), vec![1, 3]);
+        assert_eq!(collection.score_histogram(0), vec![4]);
+    }
+
+    fn member_scores(collection: &FractalCollection) -> Vec<f64> {
+        collection.members.iter().map(|m| m.fractal.resonance_score()).collect()
+    }
+
+    #[test]
+    fn sorted_by_score_ranks_members() {
+        let collection = ifs_collection(&[3, 7, 1, 5, 3]);
+        assert_eq!(member_scores(&collection.sorted_by_score(true)), vec![7.0, 5.0, 3.0, 3.0, 1.0]);
+        assert_eq!(member_scores(&collection.sorted_by_score(false)), vec![1.0, 3.0, 3.0, 5.0, 7.0]);
+
+        // Re-wrapping the sorted collection keeps the cached scores aligned with it.
+        let ranked = ResonantFractalCollection::new(collection.sorted_by_score(true));
+        assert_eq!(ranked.resonance_scores, vec![7.0, 5.0, 3.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn top_k_returns_highest_scoring_members() {
+        let collection = ifs_collection(&[4, 9, 2, 7, 5, 1, 8]);
+        assert_eq!(member_scores(&collection.top_k(3)), vec![9.0, 8.0, 7.0]);
+        assert_eq!(member_scores(&collection.top_k(1)), vec![9.0]);
+        assert!(collection.top_k(0).members.is_empty());
+        assert_eq!(collection.top_k(50).members.len(), 7);
+        assert_eq!(member_scores(&collection.top_k(50)), member_scores(&collection.sorted_by_score(true)));
+    }
+
+    /// A named fractal node with nested children, for exercising tree traversal.
+    #[derive(Debug, Clone)]
+    struct TreeNode {
+        name: &'static str,
+        children: Vec<Box<dyn Fractal>>,
+        tags: TagSet,
+        metadata: Metadata,
+    }
+
+    impl TreeNode {
+        fn new(name: &'static str, children: Vec<TreeNode>) -> Self {
+            let children = children.into_iter().map(|c| Box::new(c) as Box<dyn Fractal>).collect();
+            TreeNode { name, children, tags: TagSet::default(), metadata: Metadata::default() }
+        }
+    }
+
+    impl Fractal for TreeNode {
+        fn as_any(&self) -> &dyn std::any::Any { self }
+        fn is_equal(&self, other: &dyn Fractal) -> bool { other.id() == self.name }
+        fn resonance_law(&self) -> ResonanceLaw { ResonanceLaw::Echo }
+        fn resonance_score(&self) -> f64 { self.children.len() as f64 }
+        fn tags(&self) -> &TagSet { &self.tags }
+        fn metadata(&self) -> &Metadata { &self.metadata }
+        fn id(&self) -> &str { self.name }
+        fn children(&self) -> &[Box<dyn Fractal>] { &self.children }
+    }
+
+    #[test]
+    fn fold_fractal_walks_tree_depth_first() {
+        let tree = TreeNode::new(
+            "root",
+            vec![
+                TreeNode::new("a", vec![TreeNode::new("a1", vec![]), TreeNode::new("a2", vec![])]),
+                TreeNode::new("b", vec![TreeNode::new("b1", vec![TreeNode::new("b1x", vec![])])]),
+            ],
+        );
+
+        assert_eq!(count_nodes(&tree), 7);
+        assert_eq!(count_nodes(&IFS::default()), 1);
+
+        let order = fold_fractal(&tree, Vec::new(), |mut ids, node| {
+            ids.push(node.id().to_string());
+            ids
+        });
+        assert_eq!(order, vec!["root", "a", "a1", "a2", "b", "b1", "b1x"]);
+
+        let total_score = fold_fractal(&tree, 0.0, |sum, node| sum + node.resonance_score());
+        assert_eq!(total_score, 6.0);
+    }
+
+    #[test]
+    fn operation_weighted_resonance_respects_csg_operations() {
+        let members = vec![
+            ifs_member(5, Operation::Union),
+            ifs_member(4, Operation::Union),
+            ifs_member(3, Operation::Difference),
+            ifs_member(2, Operation::Intersection),
+            ifs_member(3, Operation::Intersection),
+        ];
+        let collection = ResonantFractalCollection::new(FractalCollection { members });
+
+        // (5 + 4 - 3) * 2 * 3
+        assert_eq!(collection.operation_weighted_resonance(), 36.0);
+        assert_eq!(ifs_collection(&[1, 2]).operation_weighted_resonance(), 3.0);
+        assert_eq!(ifs_collection(&[]).operation_weighted_resonance(), 0.0);
+
+        // With nothing to scale, intersections alone multiply together.
+        let members = vec![ifs_member(2, Operation::Intersection), ifs_member(3, Operation::Intersection)];
+        let intersections = ResonantFractalCollection::new(FractalCollection { members });
+        assert_eq!(intersections.operation_weighted_resonance(), 6.0);
+    }
+
+    #[test]
+    fn julia_equality_and_enum_delegation() {
+        let julia = Julia { c_re: 0.0, c_im: 1.0, zoom: 2.0, ..Julia::default() };
+        assert!(julia.is_equal(&julia.clone()));
+        assert!(!julia.is_equal(&Julia { c_re: 0.3, ..julia.clone() }));
+        assert!(!julia.is_equal(&Mandelbrot::default()));
+        assert_eq!(julia.resonance_score(), 0.5);
+
+        let wrapped = FractalType::Julia(julia.clone());
+        assert_eq!(wrapped.id(), "Julia");
+        assert_eq!(wrapped.resonance_law(), ResonanceLaw::FractalGrowth);
+        assert_eq!(wrapped.resonance_score(), julia.resonance_score());
+        assert_eq!(wrapped.tags(), julia.tags());
+        assert!(wrapped.children().is_empty());
+        assert!(wrapped.is_equal(&FractalType::Julia(julia)));
+        assert!(!wrapped.is_equal(&FractalType::IFS(IFS::default())));
+    }
+
+    #[test]
+    fn barnsley_fern_points_stay_within_known_bounds() {
+        let fern = AffineIFS::barnsley_fern();
+        let mut rng = StdRng::seed_from_u64(3);
+        let points = fern.sample_points(5000, &mut rng);
+
+        assert_eq!(points.len(), 5000);
+        // The fern's attractor lies within x ∈ [-2.182, 2.6558] and y ∈ [0, 9.9983].
+        assert!(points.iter().all(|&(x, y)| (-2.19..=2.66).contains(&x) && (0.0..=10.0).contains(&y)));
+        // The points spread over most of that height, rather than collapsing to a point.
+        assert!(points.iter().any(|&(_, y)| y > 8.0));
+        assert!(points.iter().any(|&(_, y)| y < 1.0));
+
+        assert!(AffineIFS::default().sample_points(10, &mut rng).is_empty());
+        assert_eq!(fern.resonance_score(), 4.0);
+        assert!(fern.is_equal(&AffineIFS::barnsley_fern()));
+    }
+
+    #[test]
+    fn mandelbrot_escape_iterations() {
+        let view = Mandelbrot { center_re: -0.5, center_im: 0.0, zoom: 2.0, ..Mandelbrot::default() };
+        // The view center maps to c = -0.5, inside the main cardioid.
+        assert_eq!(view.escape_iterations(0.0, 0.0, 200), 200);
+        // Offset (-1, 0) maps to c = -1, a period-2 point of the set.
+        assert_eq!(view.escape_iterations(-1.0, 0.0, 200), 200);
+        // Offset (20, 20) maps to c = 9.5 + 10i, which escapes after one step.
+        assert!(view.escape_iterations(20.0, 20.0, 200) <= 2);
+        // c = 0.5 lies just outside the set and takes a few steps to escape.
+        let outside = view.escape_iterations(2.0, 0.0, 200);
+        assert!(outside > 2 && outside < 200);
+        // A zero zoom is treated as no zoom.
+        assert_eq!(Mandelbrot::default().escape_iterations(0.0, 0.0, 50), 50);
+    }
+}
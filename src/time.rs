@@ -7,18 +7,88 @@ pub enum SpacetimeCoordinate {
     Sequential(f64),
     /// A 4-dimensional reality, defined by a point in Minkowski spacetime (t, x, y, z).
     Minkowski(f64, f64, f64, f64),
+    /// A reality of any other dimensionality, defined by a time component followed by
+    /// its spatial components (t, x₁, x₂, ...).
+    Manifold(Vec<f64>),
+}
+
+impl SpacetimeCoordinate {
+    /// Returns the number of dimensions (time plus space) of this coordinate.
+    pub fn dimension(&self) -> usize {
+        match self {
+            SpacetimeCoordinate::Sequential(_) => 1,
+            SpacetimeCoordinate::Minkowski(..) => 4,
+            SpacetimeCoordinate::Manifold(components) => components.len(),
+        }
+    }
+
+    /// Returns the coordinate's components, time first.
+    pub fn components(&self) -> Vec<f64> {
+        match self {
+            SpacetimeCoordinate::Sequential(time) => vec![*time],
+            SpacetimeCoordinate::Minkowski(t, x, y, z) => vec![*t, *x, *y, *z],
+            SpacetimeCoordinate::Manifold(components) => components.clone(),
+        }
+    }
+
+    /// Builds the canonical coordinate for a list of components: `Sequential` for one
+    /// component, `Minkowski` for four, and `Manifold` otherwise. An empty list gives the
+    /// zero-dimensional `Manifold(vec![])`.
+    pub fn from_components(components: Vec<f64>) -> Self {
+        match components.as_slice() {
+            [time] => SpacetimeCoordinate::Sequential(*time),
+            [t, x, y, z] => SpacetimeCoordinate::Minkowski(*t, *x, *y, *z),
+            _ => SpacetimeCoordinate::Manifold(components),
+        }
+    }
 }
 
 /// A struct that holds the current coordinate state of the simulated spacetime.
 pub struct FractalSpacetime {
     pub coordinate: SpacetimeCoordinate,
+    /// The spatial velocity `(vx, vy, vz)` applied to the first three spatial components
+    /// on each tick. It has no effect while the spacetime is still `Sequential`.
+    pub velocity: [f64; 3],
+}
+
+impl FractalSpacetime {
+    /// Creates a new `FractalSpacetime` at rest at the given coordinate.
+    pub fn new(coordinate: SpacetimeCoordinate) -> Self {
+        FractalSpacetime { coordinate, velocity: [0.0; 3] }
+    }
+
+    /// Computes the proper time `sqrt(t² - x² - y² - z²)` of the current coordinate.
+    ///
+    /// For a `Sequential` coordinate the sequence point itself is the only notion of time,
+    /// so it is returned directly. A `Manifold` coordinate subtracts all of its spatial
+    /// components. For a coordinate that is not timelike (lightlike or spacelike), there
+    /// is no real proper time and `0.0` is returned. The same holds for a zero-dimensional
+    /// `Manifold`, which has no time component at all.
+    pub fn proper_time(&self) -> f64 {
+        match &self.coordinate {
+            SpacetimeCoordinate::Sequential(time) => *time,
+            coordinate => {
+                let components = coordinate.components();
+                let Some((t, space)) = components.split_first() else {
+                    return 0.0;
+                };
+                let interval = t * t - space.iter().map(|x| x * x).sum::<f64>();
+                if interval > 0.0 { interval.sqrt() } else { 0.0 }
+            }
+        }
+    }
 }
 
 /// A trait for objects that can evolve over time.
 pub trait Evolvable {
     type EnergyOutput;
+    /// A catastrophic event that transitions the universe to `target_dim` dimensions,
+    /// releasing energy.
+    fn transition(&mut self, target_dim: usize) -> Self::EnergyOutput;
     /// A catastrophic event that transitions a 1D universe to a 4D one, releasing energy.
-    fn transition_to_4d(&mut self) -> Self::EnergyOutput;
+    fn transition_to_4d(&mut self) -> Self::EnergyOutput {
+        self.transition(4)
+    }
     /// A single step forward in time.
     fn tick(&mut self);
 }
@@ -26,21 +96,58 @@ pub trait Evolvable {
 impl Evolvable for FractalSpacetime {
     type EnergyOutput = Vec<f64>; // Represents the primordial waveform
 
-    fn transition_to_4d(&mut self) -> Self::EnergyOutput {
-        if let SpacetimeCoordinate::Sequential(initial_state) = self.coordinate {
-            self.coordinate = SpacetimeCoordinate::Minkowski(0.0, 0.0, 0.0, 0.0);
-            // The energy released is a function of the 1D state, generating a waveform.
-            vec![initial_state.sin(), initial_state.cos()] // Placeholder
-        } else {
-            // If already in 4D, no transition occurs.
-            vec![]
+    /// Transitions the spacetime to `target_dim` dimensions.
+    ///
+    /// A `Sequential` universe has no spatial extent to carry over, so the new spacetime
+    /// begins at its origin. Otherwise, existing components are kept and any new spatial
+    /// dimensions start at zero (or surplus ones are collapsed).
+    ///
+    /// The released waveform is a series of `(sin, cos)` harmonics of the source state's
+    /// time component, one pair for every three dimensions gained or lost (rounded up),
+    /// so the 1D -> 4D transition releases just the fundamental. Transitioning to the
+    /// current dimension, or to zero dimensions, is a no-op and releases nothing. A
+    /// zero-dimensional source has no time component, so its state is taken to be `0.0`.
+    fn transition(&mut self, target_dim: usize) -> Self::EnergyOutput {
+        let source_dim = self.coordinate.dimension();
+        if target_dim == 0 || target_dim == source_dim {
+            return vec![];
         }
+
+        let mut components = self.coordinate.components();
+        let initial_state = components.first().copied().unwrap_or(0.0);
+        if let SpacetimeCoordinate::Sequential(_) = self.coordinate {
+            components = vec![0.0];
+        }
+        components.resize(target_dim, 0.0);
+        self.coordinate = SpacetimeCoordinate::from_components(components);
+
+        // The energy released is a function of the source state, generating a waveform.
+        let harmonics = source_dim.abs_diff(target_dim).div_ceil(3);
+        (1..=harmonics)
+            .flat_map(|k| {
+                let phase = k as f64 * initial_state;
+                [phase.sin(), phase.cos()]
+            })
+            .collect()
     }
 
     fn tick(&mut self) {
         match &mut self.coordinate {
             SpacetimeCoordinate::Sequential(time) => *time += 1.0,
-            SpacetimeCoordinate::Minkowski(time, _, _, _) => *time += 1.0,
+            SpacetimeCoordinate::Minkowski(time, x, y, z) => {
+                *time += 1.0;
+                *x += self.velocity[0];
+                *y += self.velocity[1];
+                *z += self.velocity[2];
+            }
+            SpacetimeCoordinate::Manifold(components) => {
+                if let Some((time, space)) = components.split_first_mut() {
+                    *time += 1.0;
+                    for (x, v) in space.iter_mut().zip(self.velocity) {
+                        *x += v;
+                    }
+                }
+            }
         }
     }
 }
\ No newline at end of file
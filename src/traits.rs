@@ -21,9 +21,11 @@
 
 use crate::atom::{FractalAtom, Metadata, TagSet};
 use crate::field::FractalField;
-use crate::resonance::{ResonanceFilter, ResonanceLaw, ResonanceRule};
+use crate::fractaledge::FractalEdge;
+use crate::resonance::{PhaseShift, ResonanceFilter, ResonanceLaw, ResonanceRule, ResonantTransform};
 use crate::signature::FractalSignature;
 use num_complex::Complex;
+use rand::Rng;
 use std::any::Any;
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -103,11 +105,97 @@ pub trait Critic {
     /// Scores a field, typically based on its signature. Higher is usually better.
     fn score(&self, field: &FractalField) -> f32;
 
-    /// Classifies a field into a category like "symmetric" or "chaotic".
-    fn classify(&self, signature: &FractalSignature) -> String {
-        if signature.is_symmetric() { "symmetric".to_string() }
-        else if signature.entropy > 10.0 { "chaotic".to_string() }
-        else { "structured".to_string() }
+    /// Scores a field whose signature has already been computed.
+    ///
+    /// `CriticSuite` computes the signature once per field and calls this for each critic.
+    /// The default ignores `signature` and defers to `score`; critics that only need the
+    /// signature should override it to avoid recomputing it.
+    fn score_with_signature(&self, field: &FractalField, signature: &FractalSignature) -> f32 {
+        let _ = signature;
+        self.score(field)
+    }
+
+    /// Classifies a field into a category like `DeepSymmetric` or `ShallowChaotic`.
+    fn classify(&self, signature: &FractalSignature) -> FieldClass {
+        FieldClass::from_signature(signature)
+    }
+
+    /// A short name identifying the critic in reports. Defaults to the type's name
+    /// without its module path.
+    fn name(&self) -> &str {
+        let full = std::any::type_name::<Self>();
+        full.rsplit("::").next().unwrap_or(full)
+    }
+}
+
+/// A taxonomy of fields, derived from thresholds on their signature.
+///
+/// Fields are first split by edge count: sparse fields have too little structure to judge,
+/// and dense symmetric fields are harmonic. The rest are labelled by depth (deep or shallow)
+/// and by character (symmetric, chaotic, or structured).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldClass {
+    /// Fewer than `SPARSE_EDGE_COUNT` edges.
+    Sparse,
+    /// More than `DENSE_EDGE_COUNT` edges with a symmetric phase.
+    DenseHarmonic,
+    DeepSymmetric,
+    ShallowSymmetric,
+    DeepChaotic,
+    ShallowChaotic,
+    DeepStructured,
+    ShallowStructured,
+    /// No classification is available, e.g. from a `CriticSuite` with no critics.
+    Unclassified,
+}
+
+impl FieldClass {
+    /// Fields with fewer edges than this are `Sparse`.
+    pub const SPARSE_EDGE_COUNT: usize = 3;
+    /// Fields with more edges than this are considered dense.
+    pub const DENSE_EDGE_COUNT: usize = 20;
+    /// Fields reaching this depth or beyond are considered deep.
+    pub const DEEP_LEVEL: u32 = 3;
+    /// Fields with entropy above this are considered chaotic.
+    pub const CHAOTIC_ENTROPY: f32 = 10.0;
+
+    /// Classifies a signature using the thresholds above.
+    pub fn from_signature(signature: &FractalSignature) -> FieldClass {
+        if signature.edge_count < Self::SPARSE_EDGE_COUNT {
+            return FieldClass::Sparse;
+        }
+
+        let symmetric = signature.is_symmetric();
+        if symmetric && signature.edge_count > Self::DENSE_EDGE_COUNT {
+            return FieldClass::DenseHarmonic;
+        }
+
+        let deep = signature.depth_range.1 >= Self::DEEP_LEVEL;
+        match (symmetric, signature.entropy > Self::CHAOTIC_ENTROPY, deep) {
+            (true, _, true) => FieldClass::DeepSymmetric,
+            (true, _, false) => FieldClass::ShallowSymmetric,
+            (false, true, true) => FieldClass::DeepChaotic,
+            (false, true, false) => FieldClass::ShallowChaotic,
+            (false, false, true) => FieldClass::DeepStructured,
+            (false, false, false) => FieldClass::ShallowStructured,
+        }
+    }
+}
+
+impl std::fmt::Display for FieldClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            FieldClass::Sparse => "sparse",
+            FieldClass::DenseHarmonic => "dense-harmonic",
+            FieldClass::DeepSymmetric => "deep-symmetric",
+            FieldClass::ShallowSymmetric => "shallow-symmetric",
+            FieldClass::DeepChaotic => "deep-chaotic",
+            FieldClass::ShallowChaotic => "shallow-chaotic",
+            FieldClass::DeepStructured => "deep-structured",
+            FieldClass::ShallowStructured => "shallow-structured",
+            FieldClass::Unclassified => "unclassified",
+        };
+        write!(f, "{}", label)
     }
 }
 
@@ -115,18 +203,70 @@ pub trait Critic {
 pub struct SymmetryCritic;
 impl Critic for SymmetryCritic {
     fn score(&self, field: &FractalField) -> f32 {
-        let sig = field.signature();
+        self.score_with_signature(field, &field.signature())
+    }
+
+    fn score_with_signature(&self, _field: &FractalField, sig: &FractalSignature) -> f32 {
         let symmetry_bonus = if sig.is_symmetric() { 1.0 } else { 0.0 };
         let entropy_penalty = sig.entropy * 0.1;
         symmetry_bonus - entropy_penalty
     }
+
+    fn name(&self) -> &str {
+        "SymmetryCritic"
+    }
 }
 
 /// A simple critic that rewards high entropy.
 pub struct EntropyCritic;
 impl Critic for EntropyCritic {
     fn score(&self, field: &FractalField) -> f32 {
-        field.signature().entropy
+        self.score_with_signature(field, &field.signature())
+    }
+
+    fn score_with_signature(&self, _field: &FractalField, signature: &FractalSignature) -> f32 {
+        signature.entropy
+    }
+
+    fn name(&self) -> &str {
+        "EntropyCritic"
+    }
+}
+
+/// A critic that rewards parsimony: fields with exactly `target_edges` edges score the
+/// maximum of `0.0`, and each edge more or fewer costs one point.
+pub struct SimplicityCritic {
+    pub target_edges: usize,
+}
+impl Critic for SimplicityCritic {
+    fn score(&self, field: &FractalField) -> f32 {
+        -(field.edges.len() as f32 - self.target_edges as f32).abs()
+    }
+}
+
+/// A critic that rewards fields whose edges are phase-aligned.
+/// Scores range from 0.0 (incoherent) to 1.0 (all edges in phase).
+pub struct CoherenceCritic;
+impl Critic for CoherenceCritic {
+    fn score(&self, field: &FractalField) -> f32 {
+        field.phase_coherence()
+    }
+}
+
+/// A critic that scores a field by how much a `PhaseShift` would improve its resonance.
+///
+/// Each edge is viewed as a `FractalEdge`, and the score is the total `resonance_delta`
+/// of applying the transform. A positive score means the shift would help.
+pub struct TransformDeltaCritic {
+    pub transform: PhaseShift,
+}
+impl Critic for TransformDeltaCritic {
+    fn score(&self, field: &FractalField) -> f32 {
+        field
+            .edges
+            .iter()
+            .map(|e| self.transform.resonance_delta(&FractalEdge::from(e)))
+            .sum::<f64>() as f32
     }
 }
 
@@ -138,6 +278,13 @@ pub trait Generator {
     fn mutate(&self, field: &FractalField) -> Vec<FractalField>;
 }
 
+/// Lets boxed generators, such as those built by a `GeneratorRegistry`, drive a
+/// `GeneratorCriticLoop` directly.
+impl<G: Generator + ?Sized> Generator for Box<G> {
+    fn generate(&self) -> Vec<FractalField> { (**self).generate() }
+    fn mutate(&self, field: &FractalField) -> Vec<FractalField> { (**self).mutate(field) }
+}
+
 /// A trait for a single, specific mutation algorithm.
 pub trait MutationStrategy {
     fn mutate(&self, field: &FractalField) -> FractalField;
@@ -214,6 +361,7 @@ pub enum Operation {
 #[derive(Debug, Clone)]
 pub enum FractalType {
     Mandelbrot(Mandelbrot),
+    Julia(Julia),
     IFS(IFS),
 }
 
@@ -225,6 +373,7 @@ impl Fractal for FractalType {
     fn children(&self) -> &[Box<dyn Fractal>] {
         match self {
             FractalType::Mandelbrot(m) => m.children(),
+            FractalType::Julia(j) => j.children(),
             FractalType::IFS(i) => i.children(),
         }
     }
@@ -232,6 +381,7 @@ impl Fractal for FractalType {
     fn id(&self) -> &str {
         match self {
             FractalType::Mandelbrot(m) => m.id(),
+            FractalType::Julia(j) => j.id(),
             FractalType::IFS(i) => i.id(),
         }
     }
@@ -243,6 +393,7 @@ impl Fractal for FractalType {
             // and their inner values are equal.
             match (self, other_ft) {
                 (FractalType::Mandelbrot(m1), FractalType::Mandelbrot(m2)) => m1 == m2,
+                (FractalType::Julia(j1), FractalType::Julia(j2)) => j1 == j2,
                 (FractalType::IFS(i1), FractalType::IFS(i2)) => i1 == i2,
                 _ => false, // Variants are different types (e.g., Mandelbrot vs IFS).
             }
@@ -254,6 +405,7 @@ impl Fractal for FractalType {
     fn metadata(&self) -> &Metadata {
         match self {
             FractalType::Mandelbrot(m) => m.metadata(),
+            FractalType::Julia(j) => j.metadata(),
             FractalType::IFS(i) => i.metadata(),
         }
     }
@@ -261,6 +413,7 @@ impl Fractal for FractalType {
     fn resonance_law(&self) -> ResonanceLaw {
         match self {
             FractalType::Mandelbrot(m) => m.resonance_law(),
+            FractalType::Julia(j) => j.resonance_law(),
             FractalType::IFS(i) => i.resonance_law(),
         }
     }
@@ -268,6 +421,7 @@ impl Fractal for FractalType {
     fn resonance_score(&self) -> f64 {
         match self {
             FractalType::Mandelbrot(m) => m.resonance_score(),
+            FractalType::Julia(j) => j.resonance_score(),
             FractalType::IFS(i) => i.resonance_score(),
         }
     }
@@ -275,6 +429,7 @@ impl Fractal for FractalType {
     fn tags(&self) -> &TagSet {
         match self {
             FractalType::Mandelbrot(m) => m.tags(),
+            FractalType::Julia(j) => j.tags(),
             FractalType::IFS(i) => i.tags(),
         }
     }
@@ -323,6 +478,34 @@ impl Fractal for Mandelbrot {
     fn resonance_law(&self) -> ResonanceLaw { ResonanceLaw::Echo }
 }
 
+/// A concrete implementation of a Julia set fractal for the map `z -> z^2 + c`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Julia {
+    pub c_re: f64,
+    pub c_im: f64,
+    pub center_re: f64,
+    pub center_im: f64,
+    pub zoom: f64,
+    pub metadata: Metadata,
+    pub tags: TagSet,
+}
+
+impl Fractal for Julia {
+    fn as_any(&self) -> &dyn Any { self }
+    fn children(&self) -> &[Box<dyn Fractal>] { &[] }
+    fn id(&self) -> &str { "Julia" }
+    fn is_equal(&self, other: &dyn Fractal) -> bool {
+        other.as_any().downcast_ref::<Julia>() == Some(self)
+    }
+    fn metadata(&self) -> &Metadata { &self.metadata }
+    fn tags(&self) -> &TagSet { &self.tags }
+    fn resonance_score(&self) -> f64 {
+        // Small |c| gives connected, stable sets; the score falls towards 0 as c grows.
+        1.0 / (1.0 + self.c_re.hypot(self.c_im))
+    }
+    fn resonance_law(&self) -> ResonanceLaw { ResonanceLaw::FractalGrowth }
+}
+
 /// A concrete implementation of an Iterated Function System fractal.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct IFS {
@@ -344,6 +527,32 @@ impl Fractal for IFS {
     fn resonance_law(&self) -> ResonanceLaw { ResonanceLaw::FractalGrowth }
 }
 
+/// An Iterated Function System with explicit affine maps, computable via the chaos game.
+///
+/// Each transform `[a, b, c, d, e, f]` maps `(x, y)` to `(a·x + b·y + e, c·x + d·y + f)`.
+/// `probabilities` gives each transform's relative chance of being applied and should
+/// have one entry per transform.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AffineIFS {
+    pub transforms: Vec<[f64; 6]>,
+    pub probabilities: Vec<f64>,
+    pub metadata: Metadata,
+    pub tags: TagSet,
+}
+
+impl Fractal for AffineIFS {
+    fn as_any(&self) -> &dyn Any { self }
+    fn children(&self) -> &[Box<dyn Fractal>] { &[] }
+    fn id(&self) -> &str { "AffineIFS" }
+    fn is_equal(&self, other: &dyn Fractal) -> bool {
+        other.as_any().downcast_ref::<AffineIFS>() == Some(self)
+    }
+    fn metadata(&self) -> &Metadata { &self.metadata }
+    fn tags(&self) -> &TagSet { &self.tags }
+    fn resonance_score(&self) -> f64 { self.transforms.len() as f64 }
+    fn resonance_law(&self) -> ResonanceLaw { ResonanceLaw::FractalGrowth }
+}
+
 // --- Type-Specific Operations ---
 
 impl Mandelbrot {
@@ -359,6 +568,25 @@ impl Mandelbrot {
             tags: self.tags.clone(),
         }
     }
+
+    /// Counts the escape-time iterations of `z -> z^2 + c` for one pixel of the view.
+    ///
+    /// The pixel offset `(px, py)` from the view's center is mapped into the complex plane
+    /// as `c = center + (px + py·i) / zoom`, with a non-positive `zoom` treated as `1.0`.
+    /// Starting from `z = 0`, returns the number of iterations before `|z|` exceeds `2`,
+    /// or `max_iter` if it never does (the point is taken to be in the set).
+    pub fn escape_iterations(&self, px: f64, py: f64, max_iter: u32) -> u32 {
+        let zoom = if self.zoom > 0.0 { self.zoom } else { 1.0 };
+        let c = Complex::new(self.center_re + px / zoom, self.center_im + py / zoom);
+        let mut z = Complex::new(0.0, 0.0);
+        for i in 0..max_iter {
+            if z.norm_sqr() > 4.0 {
+                return i;
+            }
+            z = z * z + c;
+        }
+        max_iter
+    }
 }
 
 impl IFS {
@@ -372,6 +600,67 @@ impl IFS {
     }
 }
 
+impl AffineIFS {
+    /// The number of initial chaos-game points discarded while the orbit settles onto
+    /// the attractor.
+    const BURN_IN: usize = 20;
+
+    /// Creates Barnsley's fern, the classic four-map affine IFS.
+    pub fn barnsley_fern() -> AffineIFS {
+        AffineIFS {
+            transforms: vec![
+                [0.0, 0.0, 0.0, 0.16, 0.0, 0.0],
+                [0.85, 0.04, -0.04, 0.85, 0.0, 1.6],
+                [0.2, -0.26, 0.23, 0.22, 0.0, 1.6],
+                [-0.15, 0.28, 0.26, 0.24, 0.0, 0.44],
+            ],
+            probabilities: vec![0.01, 0.85, 0.07, 0.07],
+            metadata: Metadata::default(),
+            tags: TagSet::default(),
+        }
+    }
+
+    /// Runs the chaos game and returns `n` points on the attractor.
+    ///
+    /// Starting from the origin, a transform is picked at random according to
+    /// `probabilities` and applied to the current point, over and over. If the weights are
+    /// missing, mismatched or all zero, transforms are picked uniformly instead. Returns an
+    /// empty vector if there are no transforms.
+    pub fn sample_points(&self, n: usize, rng: &mut impl Rng) -> Vec<(f64, f64)> {
+        if self.transforms.is_empty() {
+            return Vec::new();
+        }
+        let total: f64 = self.probabilities.iter().sum();
+        let weighted = self.probabilities.len() == self.transforms.len() && total > 0.0;
+
+        let mut pick = || {
+            if !weighted {
+                return &self.transforms[rng.random_range(0..self.transforms.len())];
+            }
+            let mut choice = rng.random_range(0.0..total);
+            for (transform, p) in self.transforms.iter().zip(&self.probabilities) {
+                if choice < *p {
+                    return transform;
+                }
+                choice -= p;
+            }
+            // Rounding can leave `choice` just past the last weight.
+            &self.transforms[self.transforms.len() - 1]
+        };
+
+        let (mut x, mut y) = (0.0, 0.0);
+        let mut points = Vec::with_capacity(n);
+        for i in 0..n + Self::BURN_IN {
+            let [a, b, c, d, e, f] = *pick();
+            (x, y) = (a * x + b * y + e, c * x + d * y + f);
+            if i >= Self::BURN_IN {
+                points.push((x, y));
+            }
+        }
+        points
+    }
+}
+
 // --- CSG Function Implementations ---
 
 /// Combines two fractals into a `FractalCollection` representing their union.
@@ -414,6 +703,23 @@ pub fn mul_fractals(a: &FractalType, b: &FractalType) -> FractalCollection {
     }
 }
 
+// --- Tree Traversal ---
+
+/// Folds `f` over every node of a fractal tree, depth-first and in pre-order: each node is
+/// visited before its `children`, which are visited in order.
+pub fn fold_fractal<R>(root: &dyn Fractal, init: R, f: impl Fn(R, &dyn Fractal) -> R) -> R {
+    fn walk<R>(node: &dyn Fractal, acc: R, f: &dyn Fn(R, &dyn Fractal) -> R) -> R {
+        let acc = f(acc, node);
+        node.children().iter().fold(acc, |acc, child| walk(child.as_ref(), acc, f))
+    }
+    walk(root, init, &f)
+}
+
+/// Counts the nodes in a fractal tree, including the root.
+pub fn count_nodes(root: &dyn Fractal) -> usize {
+    fold_fractal(root, 0, |count, _| count + 1)
+}
+
 // --- Quantum Space & Semantic Traits ---
 
 /// Represents a fractal, infinite-dimensional quantum space.
@@ -26,6 +26,15 @@ impl Vec3 {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
 
+    /// Computes the cross product of two vectors.
+    pub fn cross(self, other: Self) -> Self {
+        Vec3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
     /// Computes the magnitude (or length) of the vector.
     pub fn norm(self) -> f32 {
         self.dot(self).sqrt()
@@ -42,6 +51,77 @@ impl Vec3 {
         }
     }
 
+    /// Computes the angle between two vectors, in radians within `[0, π]`.
+    /// Returns `0.0` if either vector has (near) zero length.
+    pub fn angle_between(self, other: Vec3) -> f32 {
+        let denom = self.norm() * other.norm();
+        if denom < 1e-12 {
+            return 0.0;
+        }
+        // Rounding can push the cosine just outside [-1, 1], where `acos` is NaN.
+        (self.dot(other) / denom).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Projects this vector onto the line spanned by `other`.
+    /// Returns a zero vector if `other` has (near) zero length.
+    pub fn project_onto(self, other: Vec3) -> Vec3 {
+        let len_sq = other.dot(other);
+        if len_sq < 1e-12 {
+            return Self::ZERO;
+        }
+        other * (self.dot(other) / len_sq)
+    }
+
+    /// Rotates the vector by `radians` about `axis` using Rodrigues' rotation formula,
+    /// counter-clockwise when looking down the axis towards the origin.
+    ///
+    /// The axis is normalized internally. A zero-length axis leaves the vector unchanged.
+    pub fn rotate_around(self, axis: Vec3, radians: f32) -> Vec3 {
+        let k = axis.normalize();
+        if k == Vec3::ZERO {
+            return self;
+        }
+        let (sin, cos) = radians.sin_cos();
+        // v·cosθ + (k × v)·sinθ + k·(k · v)(1 - cosθ)
+        self * cos + k.cross(self) * sin + k * (k.dot(self) * (1.0 - cos))
+    }
+
+    /// Spherically interpolates between the directions of `self` and `other`.
+    ///
+    /// Both vectors are normalized first, and the result moves along the great circle
+    /// between them at constant angular speed, so it stays unit length for every `t` in
+    /// `[0, 1]`. Nearly parallel inputs fall back to a normalized linear interpolation.
+    /// Nearly antiparallel inputs make the usual formula unstable, so `self` is instead
+    /// rotated towards `other`; exactly opposite inputs have no unique great circle and turn
+    /// about an arbitrary axis perpendicular to `self`. If either input has (near) zero
+    /// length there is no direction to follow, so the plain linear blend
+    /// `self * (1 - t) + other * t` is returned instead.
+    pub fn slerp(self, other: Vec3, t: f32) -> Vec3 {
+        if self.norm() < 1e-6 || other.norm() < 1e-6 {
+            return self * (1.0 - t) + other * t;
+        }
+        let (a, b) = (self.normalize(), other.normalize());
+        let (cos, mut axis) = (a.dot(b), a.cross(b));
+        // `atan2` stays accurate near 0 and π, where `acos` loses most of its precision.
+        let theta = axis.norm().atan2(cos);
+        if cos > 1.0 - 1e-5 {
+            return (a * (1.0 - t) + b * t).normalize();
+        }
+        if cos < -1.0 + 1e-5 {
+            if axis.norm() < 1e-6 {
+                axis = if a.x.abs() < 0.9 { a.cross(Vec3::X) } else { a.cross(Vec3::Y) };
+            }
+            return a.rotate_around(axis, t * theta);
+        }
+        let sin = theta.sin();
+        a * (((1.0 - t) * theta).sin() / sin) + b * ((t * theta).sin() / sin)
+    }
+
+    /// Checks whether every component differs from `other`'s by at most `eps`.
+    pub fn approx_eq(&self, other: &Vec3, eps: f32) -> bool {
+        (self.x - other.x).abs() <= eps && (self.y - other.y).abs() <= eps && (self.z - other.z).abs() <= eps
+    }
+
     /// Creates a new `Vec3` with random components in the range `[-1.0, 1.0)`.
     pub fn random() -> Self {
         let mut rng = rand::rng();
@@ -0,0 +1,83 @@
+//! Defines `Zmod`, an integer residue modulo the global `MODULUS`.
+//!
+//! This provides the ring arithmetic behind fractal addressing: every operation wraps
+//! around `MODULUS`, just as `FractalEdge` locations do. Note that `MODULUS` (256) is
+//! not prime, so `Z/256` is a ring rather than a field and only odd residues have a
+//! multiplicative inverse.
+
+use crate::constants::MODULUS;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A residue in `Z/MODULUS`. The `value` is always kept in `[0, MODULUS)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Zmod {
+    value: usize,
+}
+
+impl Zmod {
+    pub const ZERO: Zmod = Zmod { value: 0 };
+    pub const ONE: Zmod = Zmod { value: 1 };
+
+    /// Creates a new residue, reducing `value` modulo `MODULUS`.
+    pub fn new(value: usize) -> Self {
+        Zmod { value: value % MODULUS }
+    }
+
+    /// Returns the residue's representative in `[0, MODULUS)`.
+    pub fn value(self) -> usize {
+        self.value
+    }
+
+    /// Computes the multiplicative inverse using the extended Euclidean algorithm.
+    ///
+    /// Returns `None` when `value` shares a factor with `MODULUS`. Because `MODULUS` is
+    /// not prime, this includes zero and every even residue.
+    pub fn inverse(self) -> Option<Self> {
+        let (mut old_r, mut r) = (self.value as i64, MODULUS as i64);
+        let (mut old_s, mut s) = (1i64, 0i64);
+        while r != 0 {
+            let q = old_r / r;
+            (old_r, r) = (r, old_r - q * r);
+            (old_s, s) = (s, old_s - q * s);
+        }
+        // `old_r` is now gcd(value, MODULUS), and `old_s` its Bézout coefficient.
+        if old_r != 1 {
+            return None;
+        }
+        Some(Zmod::new(old_s.rem_euclid(MODULUS as i64) as usize))
+    }
+}
+
+// --- Operator Overloading ---
+
+impl Add for Zmod {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Zmod::new(self.value + other.value)
+    }
+}
+
+impl Sub for Zmod {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Zmod::new(self.value + MODULUS - other.value)
+    }
+}
+
+impl Mul for Zmod {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Zmod::new(self.value * other.value)
+    }
+}
+
+impl Neg for Zmod {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Zmod::new(MODULUS - self.value)
+    }
+}